@@ -0,0 +1,392 @@
+use crate::utils::{BigInt, SignedBigInt};
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+/// Typed answer of a problem.
+///
+/// The `Display` implementation gives the answer as expected by Project Euler.
+///
+/// # Notes
+/// Numeric answers (integers, big integers and decimals) are compared by
+/// their value, whatever their kind or scale: `5`, `5.0` and a big integer
+/// `5` are equal. They are ordered before the texts, which are compared as
+/// strings.
+///
+/// # Examples
+/// ```
+/// use reuler::Answer;
+///
+/// let answer = Answer::from(233168_usize);
+/// assert_eq!(answer, Answer::Integer(233168));
+/// assert_eq!(answer.to_string(), "233168");
+///
+/// // Some answers are a string of digits, where leading zeros matter
+/// let answer = Answer::from("0123");
+/// assert_eq!(answer.to_string(), "0123");
+///
+/// // Decimals are stored with a fixed precision
+/// let answer = Answer::decimal(31415, 4);
+/// assert_eq!(answer.to_string(), "3.1415");
+/// assert_eq!(Answer::decimal(5, 1), Answer::decimal(50, 2));
+/// assert!(Answer::decimal(5, 1) > Answer::decimal(40, 2));
+/// assert!(Answer::Integer(100) > Answer::decimal(5, 1));
+/// ```
+#[derive(Debug, Clone)]
+pub enum Answer {
+    /// An integer answer.
+    Integer(i128),
+    /// An integer answer, too large to fit in an `i128`.
+    BigInteger(BigInt),
+    /// A decimal answer with fixed precision, equal to `value / 10^scale`.
+    /// Use `Answer::decimal()` to create one: the scale should be at most
+    /// `Answer::MAX_DECIMAL_SCALE`, but larger scales are still displayed
+    /// and compared correctly.
+    Decimal { value: i128, scale: u32 },
+    /// An answer that is not a number, or where leading zeros matter (digit
+    /// strings, concatenations, etc...).
+    Text(String),
+}
+
+impl Answer {
+    /// Largest scale of a decimal answer: 10^38 is the largest power of 10
+    /// that fits in an `i128`.
+    pub const MAX_DECIMAL_SCALE: u32 = 38;
+
+    /// Create a decimal answer, equal to `value / 10^scale`.
+    ///
+    /// # Panics
+    /// Panics if `scale` is above `Answer::MAX_DECIMAL_SCALE`.
+    pub fn decimal(value: i128, scale: u32) -> Self {
+        assert!(
+            scale <= Self::MAX_DECIMAL_SCALE,
+            "The scale of a decimal is at most {} (given : {scale}).",
+            Self::MAX_DECIMAL_SCALE
+        );
+        Answer::Decimal { value, scale }
+    }
+
+    /// Return the value of the answer if it's an `Integer`, `None` otherwise.
+    pub fn as_integer(&self) -> Option<i128> {
+        match self {
+            Answer::Integer(x) => Some(*x),
+            _ => None,
+        }
+    }
+
+    /// Exact value of a numeric answer, as `numerator / 10^scale` in its
+    /// shortest form (without trailing zeros in the numerator), or `None` for
+    /// a text.
+    fn numeric_value(&self) -> Option<(SignedBigInt, u32)> {
+        let signed = |x: i128| -> SignedBigInt { x.to_string().parse().unwrap() };
+        let (mut numerator, mut scale) = match self {
+            Answer::Integer(x) => (signed(*x), 0),
+            Answer::BigInteger(x) => (SignedBigInt::from(x.clone()), 0),
+            Answer::Decimal { value, scale } => (signed(*value), *scale),
+            Answer::Text(_) => return None,
+        };
+        if numerator.is_zero() {
+            scale = 0;
+        }
+        while scale > 0 && (&numerator % 10).is_zero() {
+            numerator /= 10;
+            scale -= 1;
+        }
+        Some((numerator, scale))
+    }
+}
+
+/// Compare two numbers `numerator / 10^scale`, in their shortest form.
+fn cmp_numbers(a: &(SignedBigInt, u32), b: &(SignedBigInt, u32)) -> Ordering {
+    let sign = |x: &SignedBigInt| match (x.is_negative(), x.is_zero()) {
+        (true, _) => -1,
+        (false, true) => 0,
+        (false, false) => 1,
+    };
+    let (a_sign, b_sign) = (sign(&a.0), sign(&b.0));
+    if a_sign != b_sign || a_sign == 0 {
+        return a_sign.cmp(&b_sign);
+    }
+
+    // Compare the magnitudes by their number of digits before the decimal
+    // point first. When it's the same, the difference of scales is at most
+    // the number of digits of the numerators, so they can be brought to the
+    // same scale.
+    let integer_digits =
+        |(x, scale): &(SignedBigInt, u32)| x.magnitude().num_digits() as i64 - *scale as i64;
+    let magnitude = integer_digits(a).cmp(&integer_digits(b)).then_with(|| {
+        let scale = a.1.max(b.1);
+        let shifted =
+            |(x, s): &(SignedBigInt, u32)| x.magnitude() * &BigInt::from(10).pow(scale - s);
+        shifted(a).cmp(&shifted(b))
+    });
+    if a_sign > 0 {
+        magnitude
+    } else {
+        magnitude.reverse()
+    }
+}
+
+impl Ord for Answer {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.numeric_value(), other.numeric_value()) {
+            (Some(a), Some(b)) => cmp_numbers(&a, &b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => self.to_string().cmp(&other.to_string()),
+        }
+    }
+}
+
+impl PartialOrd for Answer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Answer {}
+
+impl Hash for Answer {
+    /// Hash the answer consistently with `Eq`: numbers are hashed by their
+    /// value in its shortest form, whatever their kind.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self.numeric_value() {
+            Some(value) => value.hash(state),
+            None => self.to_string().hash(state),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Integer(x) => write!(f, "{x}"),
+            Answer::BigInteger(x) => write!(f, "{x}"),
+            Answer::Decimal { value, scale } => {
+                let sign = if *value < 0 { "-" } else { "" };
+                let value = value.unsigned_abs();
+                if *scale == 0 {
+                    return write!(f, "{sign}{value}");
+                }
+                // Above 10^38, the unit is larger than any value
+                let (integer, fraction) = match 10_u128.checked_pow(*scale) {
+                    Some(unit) => (value / unit, value % unit),
+                    None => (0, value),
+                };
+                write!(
+                    f,
+                    "{sign}{integer}.{fraction:0width$}",
+                    width = *scale as usize
+                )
+            }
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(x: usize) -> Self {
+        Answer::Integer(x as i128)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(x: isize) -> Self {
+        Answer::Integer(x as i128)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(x: u64) -> Self {
+        Answer::Integer(i128::from(x))
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(x: i64) -> Self {
+        Answer::Integer(i128::from(x))
+    }
+}
+
+impl From<BigInt> for Answer {
+    fn from(x: BigInt) -> Self {
+        Answer::BigInteger(x)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_negative_integer() {
+        assert_eq!(Answer::from(-59231_isize).to_string(), "-59231");
+    }
+
+    #[test]
+    fn test_display_big_integer() {
+        let mut x = BigInt::from(1);
+        for _ in 0..100 {
+            x *= 2;
+        }
+        assert_eq!(
+            Answer::from(x).to_string(),
+            "1267650600228229401496703205376"
+        );
+    }
+
+    #[test]
+    fn test_display_decimal() {
+        assert_eq!(Answer::decimal(5, 3).to_string(), "0.005");
+        assert_eq!(Answer::decimal(-12345, 2).to_string(), "-123.45");
+        assert_eq!(Answer::decimal(42, 0).to_string(), "42");
+    }
+
+    #[test]
+    fn test_decimals_with_different_scales() {
+        assert_eq!(Answer::decimal(5, 1), Answer::decimal(50, 2));
+        assert_eq!(Answer::decimal(0, 0), Answer::decimal(0, 5));
+        assert_eq!(Answer::decimal(-30, 1), Answer::decimal(-3, 0));
+        assert_ne!(Answer::decimal(5, 1), Answer::decimal(5, 2));
+
+        let mut answers = [
+            Answer::decimal(40, 2),
+            Answer::decimal(5, 1),
+            Answer::decimal(-5, 1),
+            Answer::decimal(-41, 2),
+            Answer::decimal(1, 0),
+            Answer::decimal(99_999, 5),
+            Answer::decimal(i128::MAX, Answer::MAX_DECIMAL_SCALE),
+        ];
+        answers.sort();
+        let sorted: Vec<String> = answers.iter().map(|a| a.to_string()).collect();
+        assert_eq!(
+            sorted,
+            vec![
+                "-0.5",
+                "-0.41",
+                "0.40",
+                "0.5",
+                "0.99999",
+                "1",
+                "1.70141183460469231731687303715884105727",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_equal_decimals_have_the_same_hash() {
+        let mut answers = std::collections::HashSet::new();
+        answers.insert(Answer::decimal(5, 1));
+        answers.insert(Answer::decimal(500, 3));
+        answers.insert(Answer::decimal(5, 0));
+        assert_eq!(answers.len(), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_decimal_scale_too_large() {
+        Answer::decimal(1, Answer::MAX_DECIMAL_SCALE + 1);
+    }
+
+    #[test]
+    fn test_ordering() {
+        let mut answers = vec![
+            Answer::from("abc"),
+            Answer::from(12_usize),
+            Answer::from(BigInt::from(9)),
+            Answer::from(3_usize),
+        ];
+        answers.sort();
+        assert_eq!(
+            answers,
+            vec![
+                Answer::Integer(3),
+                Answer::BigInteger(BigInt::from(9)),
+                Answer::Integer(12),
+                Answer::from("abc"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_numbers_of_different_kinds() {
+        assert!(Answer::Integer(10) > Answer::BigInteger(BigInt::from(9)));
+        assert_eq!(Answer::Integer(5), Answer::BigInteger(BigInt::from(5)));
+        assert_eq!(Answer::Integer(5), Answer::decimal(50, 1));
+        assert!(Answer::Integer(100) > Answer::decimal(5, 1));
+        assert!(Answer::Integer(-1) < Answer::decimal(-5, 1));
+        let big = Answer::BigInteger(
+            "1000000000000000000000000000000000000000000"
+                .parse()
+                .unwrap(),
+        );
+        assert!(big > Answer::Integer(i128::MAX));
+        assert!(big > Answer::decimal(i128::MAX, 0));
+
+        let mut answers = std::collections::HashSet::new();
+        answers.insert(Answer::Integer(5));
+        answers.insert(Answer::BigInteger(BigInt::from(5)));
+        answers.insert(Answer::decimal(500, 2));
+        assert_eq!(answers.len(), 1);
+    }
+
+    #[test]
+    fn test_decimal_with_a_large_scale() {
+        let tiny = Answer::Decimal {
+            value: 1,
+            scale: 50,
+        };
+        assert_eq!(tiny.to_string(), format!("0.{}1", "0".repeat(49)));
+        let tinier = Answer::Decimal {
+            value: -25,
+            scale: 60,
+        };
+        assert_eq!(tinier.to_string(), format!("-0.{}25", "0".repeat(58)));
+        assert!(tinier < Answer::Integer(0));
+        assert!(Answer::Integer(0) < tiny);
+        assert!(
+            tiny > Answer::Decimal {
+                value: 99,
+                scale: 52
+            }
+        );
+        assert!(
+            tiny < Answer::Decimal {
+                value: 11,
+                scale: 51
+            }
+        );
+        assert_eq!(
+            tiny,
+            Answer::Decimal {
+                value: 100,
+                scale: 52
+            }
+        );
+        assert!(
+            Answer::Decimal {
+                value: 1,
+                scale: u32::MAX
+            } > Answer::Integer(0)
+        );
+    }
+}
@@ -0,0 +1,416 @@
+//! Benchmark mode of the command line : solvers are run several times, and
+//! statistics are computed over the runtimes.
+
+use crate::{csv_field, fmt_duration, json_string};
+use reuler::{Error, Problem};
+use std::collections::BTreeMap;
+use std::fs;
+use std::time::{Duration, Instant};
+
+/// Header of the baseline files.
+const BASELINE_HEADER: &str = "id,runs,min_ns,median_ns,mean_ns,p95_ns,stddev_ns";
+
+/// Maximum number of runs when benchmarking for a time budget, to keep the
+/// memory used by the samples bounded for very fast solvers.
+const MAX_RUNS: usize = 1_000_000;
+
+/// How a solver is benchmarked.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    /// Number of runs before starting the measures.
+    pub warmup: usize,
+    /// Number of measured runs. If `None`, the solver is run until the time
+    /// budget is spent.
+    pub runs: Option<usize>,
+    /// Time budget for the measured runs (ignored if `runs` is given). The
+    /// solver is run at least `min_runs` times, even if it exceeds the budget.
+    pub budget: Duration,
+    /// Minimum number of measured runs when using a time budget.
+    pub min_runs: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            warmup: 1,
+            runs: None,
+            budget: Duration::from_secs(1),
+            min_runs: 3,
+        }
+    }
+}
+
+/// Statistics over the runtimes of a solver, in nanoseconds.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub p95: f64,
+    pub stddev: f64,
+}
+
+impl Stats {
+    /// Compute the statistics over the given runtimes. Return `None` if there
+    /// is no sample.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut ns: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        ns.sort_by(f64::total_cmp);
+
+        let n = ns.len();
+        let mean = ns.iter().sum::<f64>() / n as f64;
+        let median = if n.is_multiple_of(2) {
+            (ns[n / 2 - 1] + ns[n / 2]) / 2.0
+        } else {
+            ns[n / 2]
+        };
+        // Nearest-rank percentile
+        let p95 = ns[((n as f64 * 0.95).ceil() as usize).clamp(1, n) - 1];
+        let stddev = if n > 1 {
+            (ns.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1) as f64).sqrt()
+        } else {
+            0.0
+        };
+
+        Some(Self {
+            runs: n,
+            min: ns[0],
+            median,
+            mean,
+            p95,
+            stddev,
+        })
+    }
+}
+
+/// Benchmark the given problem. If the solver returns an error, the benchmark
+/// is stopped and the error is returned.
+pub fn measure(problem: &dyn Problem, config: &Config) -> Result<Stats, Error> {
+    for _ in 0..config.warmup {
+        problem.solve()?;
+    }
+
+    let mut samples = Vec::new();
+    let start = Instant::now();
+    loop {
+        let done = match config.runs {
+            Some(runs) => samples.len() >= runs,
+            None => {
+                samples.len() >= MAX_RUNS
+                    || (samples.len() >= config.min_runs && start.elapsed() >= config.budget)
+            }
+        };
+        if done {
+            break;
+        }
+
+        let now = Instant::now();
+        problem.solve()?;
+        samples.push(now.elapsed());
+    }
+
+    // At least one run is always made
+    Stats::from_samples(&samples).ok_or_else(|| {
+        Error::InvalidArgument(String::from("The number of runs should be positive."))
+    })
+}
+
+/// Statistics of a previous benchmark, indexed by problem ID.
+pub type Baseline = BTreeMap<usize, Stats>;
+
+/// Format the baseline as CSV.
+pub fn format_baseline(baseline: &Baseline) -> String {
+    let mut content = String::from(BASELINE_HEADER);
+    content.push('\n');
+    for (id, s) in baseline {
+        content.push_str(&format!(
+            "{id},{},{:.0},{:.0},{:.0},{:.0},{:.0}\n",
+            s.runs, s.min, s.median, s.mean, s.p95, s.stddev
+        ));
+    }
+    content
+}
+
+/// Parse a baseline from its CSV content.
+pub fn parse_baseline(content: &str) -> Result<Baseline, String> {
+    let mut lines = content.lines();
+    if lines.next() != Some(BASELINE_HEADER) {
+        return Err(String::from(
+            "The baseline file doesn't have the expected header.",
+        ));
+    }
+
+    let mut baseline = Baseline::new();
+    for (i, line) in lines.enumerate().filter(|(_, l)| !l.trim().is_empty()) {
+        let invalid = || format!("Invalid line #{} in the baseline file : `{line}`.", i + 2);
+        let fields: Vec<&str> = line.split(',').collect();
+        if fields.len() != 7 {
+            return Err(invalid());
+        }
+        let id = fields[0].parse().map_err(|_| invalid())?;
+        let runs = fields[1].parse().map_err(|_| invalid())?;
+        let values = fields[2..]
+            .iter()
+            .map(|f| f.parse())
+            .collect::<Result<Vec<f64>, _>>()
+            .map_err(|_| invalid())?;
+
+        baseline.insert(
+            id,
+            Stats {
+                runs,
+                min: values[0],
+                median: values[1],
+                mean: values[2],
+                p95: values[3],
+                stddev: values[4],
+            },
+        );
+    }
+    Ok(baseline)
+}
+
+/// Load a baseline from a file.
+pub fn load_baseline(path: &str) -> Result<Baseline, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|err| format!("Can't read the baseline file `{path}` : {err}"))?;
+    parse_baseline(&content)
+}
+
+/// Save a baseline to a file.
+pub fn save_baseline(path: &str, baseline: &Baseline) -> Result<(), String> {
+    fs::write(path, format_baseline(baseline))
+        .map_err(|err| format!("Can't write the baseline file `{path}` : {err}"))
+}
+
+/// Relative change of the median runtime compared to the baseline, in
+/// percent (positive means slower).
+pub fn change(stats: &Stats, baseline: &Stats) -> f64 {
+    (stats.median - baseline.median) / baseline.median * 100.0
+}
+
+/// Outcome of the benchmark of one of the selected problems.
+pub enum Outcome {
+    /// The solver was benchmarked. If a baseline was given for this problem,
+    /// the relative change of the median runtime is given (in percent).
+    Measured {
+        stats: Stats,
+        change: Option<f64>,
+    },
+    Unimplemented,
+    Failed(Error),
+}
+
+/// Result of the benchmark of one of the selected problems.
+pub struct Record {
+    pub id: usize,
+    pub outcome: Outcome,
+    /// Whether the median runtime increased by more than the threshold.
+    pub regression: bool,
+}
+
+/// Header of the CSV output (see `Record::to_csv()`).
+pub const CSV_HEADER: &str =
+    "id,status,runs,min_ns,median_ns,mean_ns,p95_ns,stddev_ns,change_pct,error";
+
+/// Header of the text output (see `Record::to_text()`).
+pub fn text_header() -> String {
+    format!(
+        "{:>7} | {:>7} | {:>12} | {:>12} | {:>12} | {:>12} | {:>12} | {:>8}\n{:-<7}-+-{:-<7}-+-{:-<12}-+-{:-<12}-+-{:-<12}-+-{:-<12}-+-{:-<12}-+-{:-<8}",
+        "Problem", "Runs", "Min", "Median", "Mean", "p95", "Stddev", "Change", "", "", "", "", "", "", "", ""
+    )
+}
+
+impl Record {
+    /// Benchmark the given problem and compare it to the baseline (if any).
+    /// The threshold is given in percent.
+    pub fn bench(id: usize, config: &Config, baseline: Option<&Baseline>, threshold: f64) -> Self {
+        let Some(problem) = reuler::problems::get(id) else {
+            return Record {
+                id,
+                outcome: Outcome::Unimplemented,
+                regression: false,
+            };
+        };
+
+        match measure(problem, config) {
+            Ok(stats) => {
+                let change = baseline.and_then(|b| b.get(&id)).map(|b| change(&stats, b));
+                Record {
+                    id,
+                    regression: change.is_some_and(|c| c > threshold),
+                    outcome: Outcome::Measured { stats, change },
+                }
+            }
+            Err(err) => Record {
+                id,
+                outcome: Outcome::Failed(err),
+                regression: false,
+            },
+        }
+    }
+
+    pub fn status(&self) -> &'static str {
+        match self.outcome {
+            Outcome::Measured { .. } if self.regression => "regression",
+            Outcome::Measured { .. } => "ok",
+            Outcome::Unimplemented => "unimplemented",
+            Outcome::Failed(_) => "error",
+        }
+    }
+
+    /// Format the record as a row of the text table.
+    pub fn to_text(&self) -> String {
+        let cell = |ns: f64| fmt_duration(Duration::from_nanos(ns as u64), 12);
+        match &self.outcome {
+            Outcome::Measured { stats: s, change } => {
+                let change = change.map_or(String::new(), |c| format!("{c:+.1}%"));
+                let flag = if self.regression { "  REGRESSION" } else { "" };
+                format!(
+                    "{:>7} | {:>7} | {} | {} | {} | {} | {} | {change:>8}{flag}",
+                    self.id,
+                    s.runs,
+                    cell(s.min),
+                    cell(s.median),
+                    cell(s.mean),
+                    cell(s.p95),
+                    cell(s.stddev),
+                )
+            }
+            Outcome::Unimplemented => format!("{:>7} | (not implemented, skipped)", self.id),
+            Outcome::Failed(err) => format!("{:>7} | error : {err}", self.id),
+        }
+    }
+
+    /// Format the record as a JSON object, on a single line.
+    pub fn to_json(&self) -> String {
+        let mut json = format!("{{\"id\": {}, \"status\": \"{}\"", self.id, self.status());
+        match &self.outcome {
+            Outcome::Measured { stats: s, change } => json.push_str(&format!(
+                ", \"runs\": {}, \"min_ns\": {:.0}, \"median_ns\": {:.0}, \"mean_ns\": {:.0}, \"p95_ns\": {:.0}, \"stddev_ns\": {:.0}, \"change_pct\": {}, \"error\": null}}",
+                s.runs,
+                s.min,
+                s.median,
+                s.mean,
+                s.p95,
+                s.stddev,
+                change.map_or(String::from("null"), |c| format!("{c:.2}")),
+            )),
+            Outcome::Unimplemented => json.push_str(", \"error\": null}"),
+            Outcome::Failed(err) => {
+                json.push_str(&format!(", \"error\": {}}}", json_string(&err.to_string())))
+            }
+        }
+        json
+    }
+
+    /// Format the record as a CSV row (see `CSV_HEADER`).
+    pub fn to_csv(&self) -> String {
+        match &self.outcome {
+            Outcome::Measured { stats: s, change } => format!(
+                "{},{},{},{:.0},{:.0},{:.0},{:.0},{:.0},{},",
+                self.id,
+                self.status(),
+                s.runs,
+                s.min,
+                s.median,
+                s.mean,
+                s.p95,
+                s.stddev,
+                change.map_or(String::new(), |c| format!("{c:.2}")),
+            ),
+            Outcome::Unimplemented => format!("{},{},,,,,,,,", self.id, self.status()),
+            Outcome::Failed(err) => format!(
+                "{},{},,,,,,,,{}",
+                self.id,
+                self.status(),
+                csv_field(&err.to_string())
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples: Vec<Duration> = [4, 1, 3, 2, 100].map(Duration::from_nanos).to_vec();
+        let stats = Stats::from_samples(&samples).unwrap();
+
+        assert_eq!(stats.runs, 5);
+        assert_eq!(stats.min, 1.0);
+        assert_eq!(stats.median, 3.0);
+        assert_eq!(stats.mean, 22.0);
+        assert_eq!(stats.p95, 100.0);
+        assert!((stats.stddev - 43.6).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_stats_even_samples() {
+        let samples: Vec<Duration> = [4, 1, 3, 2].map(Duration::from_nanos).to_vec();
+        let stats = Stats::from_samples(&samples).unwrap();
+
+        assert_eq!(stats.median, 2.5);
+        assert_eq!(stats.p95, 4.0);
+    }
+
+    #[test]
+    fn test_stats_single_sample() {
+        let stats = Stats::from_samples(&[Duration::from_nanos(7)]).unwrap();
+        assert_eq!(stats.stddev, 0.0);
+        assert_eq!(stats.p95, 7.0);
+        assert!(Stats::from_samples(&[]).is_none());
+    }
+
+    #[test]
+    fn test_measure_runs() {
+        let config = Config {
+            runs: Some(4),
+            ..Config::default()
+        };
+        let problem = reuler::problems::get(1).unwrap();
+        assert_eq!(measure(problem, &config).unwrap().runs, 4);
+    }
+
+    #[test]
+    fn test_baseline_roundtrip() {
+        let mut baseline = Baseline::new();
+        baseline.insert(
+            1,
+            Stats {
+                runs: 10,
+                min: 100.0,
+                median: 120.0,
+                mean: 130.0,
+                p95: 200.0,
+                stddev: 15.0,
+            },
+        );
+
+        let content = format_baseline(&baseline);
+        assert_eq!(parse_baseline(&content).unwrap(), baseline);
+        assert!(parse_baseline("id,median\n1,120").is_err());
+        assert!(parse_baseline(&format!("{BASELINE_HEADER}\n1,2,3")).is_err());
+    }
+
+    #[test]
+    fn test_change() {
+        let stats = |median| Stats {
+            runs: 1,
+            min: median,
+            median,
+            mean: median,
+            p95: median,
+            stddev: 0.0,
+        };
+        assert_eq!(change(&stats(150.0), &stats(100.0)), 50.0);
+        assert_eq!(change(&stats(80.0), &stats(100.0)), -20.0);
+    }
+}
@@ -0,0 +1,60 @@
+use std::fmt;
+use std::time::Duration;
+
+/// Errors that can happen while solving a problem.
+///
+/// # Examples
+/// ```
+/// use reuler::Error;
+///
+/// assert_eq!(reuler::solve(-1), Err(Error::InvalidId(-1)));
+/// assert_eq!(reuler::solve(9999999), Err(Error::NotImplemented(9999999)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The given problem ID is not valid (0 or negative).
+    InvalidId(isize),
+    /// The problem with this ID doesn't have a solution yet.
+    NotImplemented(usize),
+    /// The input data of the problem couldn't be parsed.
+    MalformedInput(String),
+    /// An argument given to a solver is outside of the range it can handle.
+    InvalidArgument(String),
+    /// An intermediate result doesn't fit in the integer type used by the
+    /// solver.
+    Overflow(String),
+    /// The solver didn't finish within the given time.
+    Timeout(Duration),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::InvalidId(id) => write!(f, "The provided problem ID is not valid (0 or negative number : `{id}`). Please provide a valid ID."),
+            Error::NotImplemented(id) => write!(f, "The solution for the problem #{id} is not yet implemented. Consider contributing !"),
+            Error::MalformedInput(msg) => write!(f, "Malformed input data : {msg}"),
+            Error::InvalidArgument(msg) => write!(f, "Invalid argument : {msg}"),
+            Error::Overflow(msg) => write!(f, "Arithmetic overflow : {msg}"),
+            Error::Timeout(limit) => write!(f, "The solver didn't finish within the time limit ({limit:?})."),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert!(Error::InvalidId(-1).to_string().contains("not valid"));
+        assert!(Error::NotImplemented(1000)
+            .to_string()
+            .contains("not yet implemented"));
+        assert_eq!(
+            Error::MalformedInput(String::from("Unknown card suit : X")).to_string(),
+            "Malformed input data : Unknown card suit : X"
+        );
+    }
+}
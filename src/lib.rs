@@ -11,6 +11,21 @@
 //! println!("Solution : {res}");
 //! ```
 //!
+//! ## Browsing the problems
+//! Every implemented problem comes with its metadata (title, link, difficulty,
+//! tags and `utils` helpers used), which can be queried :
+//!
+//! ```rust
+//! use reuler::{problems, Tag, Technique};
+//!
+//! for p in problems::tagged(Tag::Primes) {
+//!     println!("#{} {} ({})", p.id(), p.title(), p.metadata().url());
+//! }
+//!
+//! let big_int_examples = problems::using(Technique::BigInt);
+//! assert!(!big_int_examples.is_empty());
+//! ```
+//!
 //! ## Example of command line usage
 //! You can also call the command line `reuler` directly to get the result.
 //!
@@ -21,92 +36,316 @@
 //! Will print :
 //! > Solution for problem #1 : 233168  
 //! > Time taken : 83.208µs
+//!
+//! Several problems can be solved at once, with a summary table :
+//!
+//! ```console
+//! reuler run 1-10,67
+//! reuler run --all
+//! ```
 
+pub mod answer;
+pub mod error;
+pub mod params;
 pub mod problems;
 pub mod utils;
+pub mod verify;
+
+pub use answer::Answer;
+pub use error::Error;
+pub use params::{Param, Params, Value};
+pub use problems::{Metadata, Problem, Tag, Technique};
+pub use verify::Verdict;
+
+use std::collections::BTreeMap;
+use std::panic;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Return all the problems that have an implementation, sorted by ID.
+///
+/// # Examples
+/// ```
+/// let ids: Vec<usize> = reuler::problems().iter().map(|p| p.id()).collect();
+///
+/// assert_eq!(ids[0], 1);
+/// assert!(ids.contains(&67));
+/// ```
+pub fn problems() -> &'static [&'static dyn Problem] {
+    problems::all()
+}
+
+/// Find the problem with the given ID.
+fn find(problem_id: isize) -> Result<&'static dyn Problem, Error> {
+    if problem_id < 1 {
+        return Err(Error::InvalidId(problem_id));
+    }
+
+    let problem_id = problem_id.unsigned_abs();
+    problems::get(problem_id).ok_or(Error::NotImplemented(problem_id))
+}
 
-/// Solve the given problem and return the solution as a string.
+/// Solve the given problem and return the solution.
 ///
 /// # Errors
 /// If the given problem ID is invalid or doesn't have an implementation yet,
-/// an error is returned.
-pub fn solve(problem_id: isize) -> Result<String, String> {
-    if problem_id < 1 {
-        return Err(format!("The provided problem ID is not valid (0 or negative number : `{problem_id}`). Please provide a valid ID."));
+/// an error is returned. An error is also returned if the solver itself fails
+/// (see [`Error`]).
+pub fn solve(problem_id: isize) -> Result<Answer, Error> {
+    find(problem_id)?.solve()
+}
+
+/// Solve the given problem with custom parameters, and return the solution.
+/// The parameters accepted by each problem (and their default values) can be
+/// listed with `Problem::params()`.
+///
+/// # Examples
+/// ```
+/// use reuler::Params;
+///
+/// // Problem #1 with a different limit
+/// let params = Params::new().set("limit", 10);
+/// assert_eq!(reuler::solve_with(1, &params).unwrap().to_string(), "23");
+///
+/// // Problem #31 with US coins, to make one dollar
+/// let params = Params::new()
+///     .set("coins", vec![1, 5, 10, 25, 50, 100])
+///     .set("goal", 100);
+/// assert_eq!(reuler::solve_with(31, &params).unwrap().to_string(), "293");
+/// ```
+///
+/// # Errors
+/// Same as `solve()`. An error is also returned if a parameter is unknown, of
+/// the wrong kind, or has an invalid value.
+pub fn solve_with(problem_id: isize, params: &Params) -> Result<Answer, Error> {
+    find(problem_id)?.solve_with(params)
+}
+
+/// Solve the given problem on a worker thread, and return the solution if it
+/// was found within the given time.
+///
+/// # Notes
+/// Threads can't be killed : when the time is exceeded, the worker thread
+/// keeps running in the background until the solver finishes (or the program
+/// exits), and its result is discarded.
+///
+/// # Examples
+/// ```
+/// use std::time::Duration;
+///
+/// let res = reuler::solve_with_timeout(1, Duration::from_secs(60));
+/// assert_eq!(res.unwrap().to_string(), "233168");
+/// ```
+///
+/// # Errors
+/// Same as `solve()`. If the solver doesn't finish in time,
+/// `Error::Timeout` is returned.
+///
+/// # Panics
+/// If the solver panics, the panic is propagated to the caller.
+pub fn solve_with_timeout(problem_id: isize, timeout: Duration) -> Result<Answer, Error> {
+    timed_solve(find(problem_id)?, timeout)
+}
+
+/// Solve the given problem on a worker thread, within the given time (see
+/// `solve_with_timeout()`).
+fn timed_solve(problem: &'static dyn Problem, timeout: Duration) -> Result<Answer, Error> {
+    let (sender, receiver) = mpsc::channel();
+    let worker = thread::spawn(move || {
+        // The receiver is dropped if the time is exceeded, ignore the error
+        let _ = sender.send(problem.solve());
+    });
+
+    match receiver.recv_timeout(timeout) {
+        Ok(res) => res,
+        Err(RecvTimeoutError::Timeout) => Err(Error::Timeout(timeout)),
+        Err(RecvTimeoutError::Disconnected) => match worker.join() {
+            Err(payload) => panic::resume_unwind(payload),
+            Ok(()) => unreachable!("The worker thread finished without sending a result."),
+        },
     }
+}
+
+/// Result of one of the problems solved by `solve_many()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    /// ID of the problem.
+    pub id: usize,
+    /// Solution of the problem, or the error returned while solving it.
+    pub result: Result<Answer, Error>,
+    /// Wall time taken to solve the problem.
+    pub elapsed: Duration,
+}
 
-    match problem_id {
-        1 => return Ok(problems::prob_1::solve()),
-        2 => return Ok(problems::prob_2::solve()),
-        3 => return Ok(problems::prob_3::solve()),
-        4 => return Ok(problems::prob_4::solve()),
-        5 => return Ok(problems::prob_5::solve()),
-        6 => return Ok(problems::prob_6::solve()),
-        7 => return Ok(problems::prob_7::solve()),
-        8 => return Ok(problems::prob_8::solve()),
-        9 => return Ok(problems::prob_9::solve()),
-        10 => return Ok(problems::prob_10::solve()),
-        11 => return Ok(problems::prob_11::solve()),
-        12 => return Ok(problems::prob_12::solve()),
-        13 => return Ok(problems::prob_13::solve()),
-        14 => return Ok(problems::prob_14::solve()),
-        15 => return Ok(problems::prob_15::solve()),
-        16 => return Ok(problems::prob_16::solve()),
-        17 => return Ok(problems::prob_17::solve()),
-        18 => return Ok(problems::prob_18::solve()),
-        19 => return Ok(problems::prob_19::solve()),
-        20 => return Ok(problems::prob_20::solve()),
-        21 => return Ok(problems::prob_21::solve()),
-        22 => return Ok(problems::prob_22::solve()),
-        23 => return Ok(problems::prob_23::solve()),
-        24 => return Ok(problems::prob_24::solve()),
-        25 => return Ok(problems::prob_25::solve()),
-        26 => return Ok(problems::prob_26::solve()),
-        27 => return Ok(problems::prob_27::solve()),
-        28 => return Ok(problems::prob_28::solve()),
-        29 => return Ok(problems::prob_29::solve()),
-        30 => return Ok(problems::prob_30::solve()),
-        31 => return Ok(problems::prob_31::solve()),
-        32 => return Ok(problems::prob_32::solve()),
-        33 => return Ok(problems::prob_33::solve()),
-        34 => return Ok(problems::prob_34::solve()),
-        35 => return Ok(problems::prob_35::solve()),
-        36 => return Ok(problems::prob_36::solve()),
-        37 => return Ok(problems::prob_37::solve()),
-        38 => return Ok(problems::prob_38::solve()),
-        39 => return Ok(problems::prob_39::solve()),
-        40 => return Ok(problems::prob_40::solve()),
-        41 => return Ok(problems::prob_41::solve()),
-        42 => return Ok(problems::prob_42::solve()),
-        43 => return Ok(problems::prob_43::solve()),
-        44 => return Ok(problems::prob_44::solve()),
-        45 => return Ok(problems::prob_45::solve()),
-        46 => return Ok(problems::prob_46::solve()),
-        47 => return Ok(problems::prob_47::solve()),
-        48 => return Ok(problems::prob_48::solve()),
-        49 => return Ok(problems::prob_49::solve()),
-        50 => return Ok(problems::prob_50::solve()),
-        51 => return Ok(problems::prob_51::solve()),
-        52 => return Ok(problems::prob_52::solve()),
-        53 => return Ok(problems::prob_53::solve()),
-        54 => return Ok(problems::prob_54::solve()),
-        55 => return Ok(problems::prob_55::solve()),
-        56 => return Ok(problems::prob_56::solve()),
-        57 => return Ok(problems::prob_57::solve()),
-        58 => return Ok(problems::prob_58::solve()),
-        59 => return Ok(problems::prob_59::solve()),
-        67 => return Ok(problems::prob_67::solve()),
-        _ => return Err(format!("The solution for the problem #{problem_id} is not yet implemented. Consider contributing !")),
+impl Run {
+    /// Solve the given problem (within the given time, if any, see
+    /// `solve_with_timeout()`), timing the runtime.
+    pub fn solve(id: usize, timeout: Option<Duration>) -> Self {
+        let now = Instant::now();
+        let result = match (problems::get(id), timeout) {
+            (None, _) => Err(Error::NotImplemented(id)),
+            (Some(problem), Some(timeout)) => timed_solve(problem, timeout),
+            (Some(problem), None) => problem.solve(),
+        };
+        Run {
+            id,
+            result,
+            elapsed: now.elapsed(),
+        }
     }
 }
 
+/// Solve the given problems, using `jobs` worker threads, and return the
+/// results in the same order as the given IDs. If `jobs` is 0, one worker per
+/// CPU core is used.
+///
+/// # Examples
+/// ```
+/// let runs = reuler::solve_many(&[1, 2, 9999999], 2);
+///
+/// assert_eq!(runs[0].result.as_ref().unwrap().to_string(), "233168");
+/// assert_eq!(runs[1].result.as_ref().unwrap().to_string(), "4613732");
+/// assert_eq!(runs[2].result, Err(reuler::Error::NotImplemented(9999999)));
+/// ```
+pub fn solve_many(ids: &[usize], jobs: usize) -> Vec<Run> {
+    let mut runs = Vec::with_capacity(ids.len());
+    solve_each(ids, jobs, None, |run| runs.push(run));
+    runs
+}
+
+/// Solve the given problems, using `jobs` worker threads (one per CPU core if
+/// `jobs` is 0), with an optional time limit per problem (see
+/// `solve_with_timeout()`).
+///
+/// `on_run` is called from the calling thread for each problem, in the same
+/// order as the given IDs, as soon as the problem and all the previous ones
+/// are solved.
+pub fn solve_each(
+    ids: &[usize],
+    jobs: usize,
+    timeout: Option<Duration>,
+    mut on_run: impl FnMut(Run),
+) {
+    let jobs = match jobs {
+        0 => thread::available_parallelism().map_or(1, |n| n.get()),
+        n => n,
+    };
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(ids.len()) {
+            let sender = sender.clone();
+            let next = &next;
+            scope.spawn(move || loop {
+                // Each worker takes the next problem to solve, until there is
+                // none left
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(&id) = ids.get(i) else {
+                    break;
+                };
+                if sender.send((i, Run::solve(id, timeout))).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+
+        // Problems may finish out of order : keep the results until all the
+        // previous problems are solved
+        let mut pending = BTreeMap::new();
+        let mut next_to_report = 0;
+        for (i, run) in receiver {
+            pending.insert(i, run);
+            while let Some(run) = pending.remove(&next_to_report) {
+                on_run(run);
+                next_to_report += 1;
+            }
+        }
+    });
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_solve_problem_1() {
-        assert_eq!(solve(1).unwrap(), "233168");
+        assert_eq!(solve(1).unwrap(), Answer::Integer(233168));
+        assert_eq!(solve(1).unwrap().to_string(), "233168");
+    }
+
+    #[test]
+    fn test_solve_with_custom_params() {
+        let params = Params::new().set("limit", 10);
+        assert_eq!(solve_with(1, &params).unwrap(), Answer::Integer(23));
+    }
+
+    #[test]
+    fn test_solve_with_unknown_param() {
+        let params = Params::new().set("unknown", 10);
+        assert!(matches!(
+            solve_with(1, &params),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_solve_with_timeout() {
+        assert_eq!(
+            solve_with_timeout(1, Duration::from_secs(60)),
+            Ok(Answer::Integer(233168))
+        );
+        assert_eq!(
+            solve_with_timeout(-1, Duration::from_secs(60)),
+            Err(Error::InvalidId(-1))
+        );
+    }
+
+    /// Solver that never finishes, to test the timeouts.
+    struct Stuck;
+
+    impl Problem for Stuck {
+        fn metadata(&self) -> &'static Metadata {
+            &Metadata {
+                id: 0,
+                title: "Stuck",
+                difficulty: 1,
+                tags: &[],
+                techniques: &[],
+            }
+        }
+
+        fn compute(&self, _params: &Params) -> Result<Answer, Error> {
+            loop {
+                thread::park();
+            }
+        }
+    }
+
+    #[test]
+    fn test_timed_solve_times_out() {
+        let timeout = Duration::from_millis(10);
+        assert_eq!(timed_solve(&Stuck, timeout), Err(Error::Timeout(timeout)));
+    }
+
+    #[test]
+    fn test_solve_many_keeps_order() {
+        let ids = [5, 1, 9999999, 2, 3];
+        for jobs in [0, 1, 3, 10] {
+            let runs = solve_many(&ids, jobs);
+            let run_ids: Vec<usize> = runs.iter().map(|r| r.id).collect();
+            assert_eq!(run_ids, ids);
+            assert_eq!(runs[1].result, Ok(Answer::Integer(233168)));
+            assert_eq!(runs[2].result, Err(Error::NotImplemented(9999999)));
+        }
+    }
+
+    #[test]
+    fn test_solve_many_empty() {
+        assert!(solve_many(&[], 4).is_empty());
     }
 
     #[test]
@@ -116,7 +355,7 @@ mod tests {
                 false,
                 "No error raised, even though the given ID was invalid."
             ),
-            Err(e) => assert!(e.contains("not valid"), "Wrong error message"),
+            Err(e) => assert_eq!(e, Error::InvalidId(-1), "Wrong error"),
         }
     }
 
@@ -127,7 +366,7 @@ mod tests {
                 false,
                 "No error raised, even though the given ID was invalid."
             ),
-            Err(e) => assert!(e.contains("not yet implemented"), "Wrong error message"),
+            Err(e) => assert_eq!(e, Error::NotImplemented(9999999), "Wrong error"),
         }
     }
 }
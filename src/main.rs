@@ -1,34 +1,883 @@
-use reuler;
+mod bench;
+
+use reuler::{verify, Answer, Error, Run, Verdict};
+use std::cmp::Reverse;
+use std::collections::HashSet;
 use std::env;
 use std::process;
-use std::time;
+use std::time::{Duration, Instant};
+
+const USAGE: &str = "Usage :
+    reuler <problem_id>           Solve a single problem
+    reuler run <selection>        Solve several problems (`1-59`, `1,5,67`, `1-10,67`...)
+    reuler run --all              Solve every implemented problem
+    reuler bench <selection>      Benchmark several problems (or `--all`)
+    reuler verify [<selection>]   Check the answers against the known-correct ones (default : all)
+
+Options :
+    --format <text|json|csv>      Output format (default : text)
+    --timeout <duration>          Time limit per problem, like `60s` (not for `bench`)
+    --jobs <n>                    Number of problems solved in parallel, 0 for one per CPU core
+                                  (default : 1, not for `bench`)
+    -h, --help                    Print this message
+
+Benchmark options :
+    --runs <n>                    Number of measured runs (default : run for the time budget)
+    --time <duration>             Time budget per problem, like `500ms` or `2s` (default : 1s)
+    --warmup <n>                  Number of runs before measuring (default : 1)
+    --save <file>                 Save the results as a baseline file
+    --baseline <file>             Compare the results to a baseline file
+    --threshold <percent>         Slowdown of the median flagged as a regression (default : 10)
+
+Exit codes :
+    0    Every selected problem was solved
+    1    At least one solver returned an error or timed out
+    2    The arguments are invalid
+    3    Some selected problems are not implemented (the others were solved)
+    4    A benchmark is slower than its baseline (above the threshold)
+    5    A wrong answer was found by `verify`";
+
+/// Exit code when every selected problem was solved.
+const EXIT_OK: i32 = 0;
+/// Exit code when at least one solver returned an error.
+const EXIT_ERROR: i32 = 1;
+/// Exit code when the arguments can't be parsed.
+const EXIT_USAGE: i32 = 2;
+/// Exit code when some selected problems are not implemented.
+const EXIT_UNIMPLEMENTED: i32 = 3;
+/// Exit code when a benchmark regressed compared to its baseline.
+const EXIT_REGRESSION: i32 = 4;
+/// Exit code when a wrong answer was found.
+const EXIT_WRONG: i32 = 5;
+
+/// Default regression threshold of the benchmarks, in percent.
+const DEFAULT_THRESHOLD: f64 = 10.0;
+
+/// Number of problems listed as the slowest ones in the summary.
+const N_SLOWEST: usize = 3;
+
+/// Options that are only valid for the benchmark mode.
+const BENCH_OPTIONS: &[&str] = &[
+    "--runs",
+    "--time",
+    "--warmup",
+    "--save",
+    "--baseline",
+    "--threshold",
+];
+
+/// Header of the CSV output.
+const CSV_HEADER: &str = "id,status,answer,elapsed_ns,error";
+
+/// Print an error message about the arguments and exit.
+fn usage_error(msg: &str) -> ! {
+    eprintln!("{msg}\n\n{USAGE}");
+    process::exit(EXIT_USAGE);
+}
+
+/// Output format of the command line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl Format {
+    fn parse(s: &str) -> Result<Self, String> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "Unknown output format `{s}`. Please use `text`, `json` or `csv`."
+            )),
+        }
+    }
+}
+
+/// What the command line was asked to do.
+#[derive(Debug, PartialEq, Eq)]
+enum Command {
+    /// Solve a single problem.
+    Solve(isize),
+    /// Solve several problems, given by ID.
+    Run(Vec<usize>),
+    /// Benchmark several problems, given by ID.
+    Bench(Vec<usize>),
+    /// Check the answers of several problems, given by ID.
+    Verify(Vec<usize>),
+    /// Print the usage.
+    Help,
+}
+
+/// Parsed command line arguments.
+#[derive(Debug, PartialEq)]
+struct Options {
+    command: Command,
+    format: Format,
+    bench: bench::Config,
+    /// File where to save the benchmark results.
+    save: Option<String>,
+    /// File containing the baseline to compare the benchmark results to.
+    baseline: Option<String>,
+    /// Regression threshold of the benchmarks, in percent.
+    threshold: f64,
+    /// Time limit for each problem.
+    timeout: Option<Duration>,
+    /// Number of problems solved in parallel (0 for one per CPU core).
+    jobs: usize,
+}
+
+/// Parse a duration, given in seconds or with a unit (`ms`, `s`, `m`).
+fn parse_duration(s: &str) -> Result<Duration, String> {
+    let invalid = || format!("`{s}` is not a valid duration (for example : `500ms`, `2s`, `1m`).");
+    let (value, unit) = match s.find(|c: char| c.is_ascii_alphabetic()) {
+        Some(i) => s.split_at(i),
+        None => (s, "s"),
+    };
+    let value: f64 = value.parse().map_err(|_| invalid())?;
+    let secs = match unit {
+        "ms" => value / 1000.0,
+        "s" => value,
+        "m" => value * 60.0,
+        _ => return Err(invalid()),
+    };
+    Duration::try_from_secs_f64(secs).map_err(|_| invalid())
+}
+
+/// Parse a positive integer given to an option.
+fn parse_positive(name: &str, s: &str) -> Result<usize, String> {
+    match s.parse() {
+        Ok(n) if n > 0 => Ok(n),
+        _ => Err(format!(
+            "The value of `{name}` should be a positive integer (given : `{s}`)."
+        )),
+    }
+}
+
+/// Parse the command line arguments (without the program name).
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut format = Format::Text;
+    let mut all = false;
+    let mut positionals = Vec::new();
+    let mut bench = bench::Config::default();
+    let mut bench_options = Vec::new();
+    let mut save = None;
+    let mut baseline = None;
+    let mut threshold = DEFAULT_THRESHOLD;
+    let mut timeout = None;
+    let mut jobs = 1;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        // Options can be given as `--name value` or `--name=value`
+        let (name, inline_value) = match arg.split_once('=') {
+            Some((name, value)) if arg.starts_with("--") => (name, Some(value.to_string())),
+            _ => (arg.as_str(), None),
+        };
+        let mut value = || {
+            inline_value
+                .clone()
+                .or_else(|| args.next().cloned())
+                .ok_or_else(|| format!("Missing the value of `{name}`."))
+        };
+
+        match name {
+            "-h" | "--help" => {
+                return Ok(Options {
+                    command: Command::Help,
+                    format,
+                    bench,
+                    save,
+                    baseline,
+                    threshold,
+                    timeout,
+                    jobs,
+                })
+            }
+            "--all" => all = true,
+            "--format" => format = Format::parse(&value()?)?,
+            "--runs" => bench.runs = Some(parse_positive(name, &value()?)?),
+            "--time" => bench.budget = parse_duration(&value()?)?,
+            "--warmup" => {
+                let v = value()?;
+                bench.warmup = v.parse().map_err(|_| {
+                    format!("The value of `{name}` should be an integer (given : `{v}`).")
+                })?
+            }
+            "--timeout" => timeout = Some(parse_duration(&value()?)?),
+            "--jobs" => {
+                let v = value()?;
+                jobs = v.parse().map_err(|_| {
+                    format!("The value of `{name}` should be an integer (given : `{v}`).")
+                })?
+            }
+            "--save" => save = Some(value()?),
+            "--baseline" => baseline = Some(value()?),
+            "--threshold" => {
+                let v = value()?;
+                threshold = v.parse().map_err(|_| {
+                    format!("The value of `{name}` should be a number (given : `{v}`).")
+                })?
+            }
+            a if a.starts_with("--") => return Err(format!("Unknown option `{a}`.")),
+            a => {
+                positionals.push(a);
+                continue;
+            }
+        }
+        if BENCH_OPTIONS.contains(&name) {
+            bench_options.push(name);
+        }
+    }
+
+    let selection = |selection: &[&str]| match selection {
+        [] if all => Ok(reuler::problems().iter().map(|p| p.id()).collect()),
+        [] => Err(String::from("Missing the problems to run.")),
+        [selection] if !all => parse_selection(selection),
+        _ => Err(String::from("Too many arguments.")),
+    };
+
+    let command = match positionals.as_slice() {
+        [] => return Err(String::from("Not enough arguments.")),
+        ["run", rest @ ..] => Command::Run(selection(rest)?),
+        ["bench", rest @ ..] => Command::Bench(selection(rest)?),
+        ["verify"] => Command::Verify(reuler::problems().iter().map(|p| p.id()).collect()),
+        ["verify", rest @ ..] => Command::Verify(selection(rest)?),
+        [id] if !all => Command::Solve(id.trim().parse().map_err(|_err| {
+            format!("The given argument is not an ID (`{id}`). Please provide a number.")
+        })?),
+        _ => return Err(String::from("Too many arguments.")),
+    };
+
+    if let (Some(name), false) = (bench_options.first(), matches!(command, Command::Bench(_))) {
+        return Err(format!(
+            "The option `{name}` can only be used with `bench`."
+        ));
+    }
+    if matches!(command, Command::Bench(_)) {
+        if timeout.is_some() {
+            return Err(String::from(
+                "The option `--timeout` can't be used with `bench`.",
+            ));
+        }
+        if jobs != 1 {
+            return Err(String::from(
+                "The option `--jobs` can't be used with `bench`.",
+            ));
+        }
+    }
+
+    Ok(Options {
+        command,
+        format,
+        bench,
+        save,
+        baseline,
+        threshold,
+        timeout,
+        jobs,
+    })
+}
+
+/// Parse a selection of problem IDs, made of comma-separated IDs or ranges of
+/// IDs (bounds included). IDs are returned in the given order, without
+/// duplicates.
+fn parse_selection(selection: &str) -> Result<Vec<usize>, String> {
+    let parse_id = |s: &str| -> Result<usize, String> {
+        match s.trim().parse() {
+            Ok(id) if id > 0 => Ok(id),
+            _ => Err(format!(
+                "`{}` is not a valid problem ID. Please provide a positive number.",
+                s.trim()
+            )),
+        }
+    };
+
+    let mut ids = Vec::new();
+    let mut seen = HashSet::new();
+    for item in selection.split(',') {
+        let range = match item.split_once('-') {
+            Some((start, end)) => {
+                let (start, end) = (parse_id(start)?, parse_id(end)?);
+                if start > end {
+                    return Err(format!("`{}` is not a valid range of IDs.", item.trim()));
+                }
+                start..=end
+            }
+            None => {
+                let id = parse_id(item)?;
+                id..=id
+            }
+        };
+
+        ids.extend(range.filter(|&id| seen.insert(id)));
+    }
+    Ok(ids)
+}
+
+/// Outcome of one of the selected problems.
+enum Outcome {
+    Solved(Answer),
+    Unimplemented,
+    Failed(Error),
+}
+
+/// Result of one of the selected problems, as reported by the command line.
+struct Record {
+    id: usize,
+    outcome: Outcome,
+    elapsed: Option<Duration>,
+    /// Verdict of the answer, when verifying.
+    verdict: Option<Verdict>,
+}
+
+impl Record {
+    /// Solve the given problem, timing the runtime. If a timeout is given,
+    /// the problem is solved on a worker thread and abandoned when the time
+    /// is exceeded.
+    fn solve(id: usize, timeout: Option<Duration>) -> Self {
+        Record::from(Run::solve(id, timeout))
+    }
+
+    /// Check the answer against the known-correct one.
+    fn checked(mut self) -> Self {
+        if let Outcome::Solved(answer) = &self.outcome {
+            self.verdict = Some(verify::check(self.id, answer));
+        }
+        self
+    }
+
+    fn status(&self) -> &'static str {
+        match self.outcome {
+            Outcome::Solved(_) => self.verdict.map_or("ok", |v| v.name()),
+            Outcome::Unimplemented => "unimplemented",
+            Outcome::Failed(Error::Timeout(_)) => "timeout",
+            Outcome::Failed(_) => "error",
+        }
+    }
+
+    fn answer(&self) -> Option<String> {
+        match &self.outcome {
+            Outcome::Solved(answer) => Some(answer.to_string()),
+            _ => None,
+        }
+    }
+
+    fn error(&self) -> Option<String> {
+        match &self.outcome {
+            Outcome::Failed(err) => Some(err.to_string()),
+            _ => None,
+        }
+    }
+
+    /// Format the record as a JSON object, on a single line.
+    fn to_json(&self) -> String {
+        let string_or_null =
+            |s: Option<String>| s.map_or(String::from("null"), |s| json_string(&s));
+        format!(
+            "{{\"id\": {}, \"status\": \"{}\", \"answer\": {}, \"elapsed_ns\": {}, \"error\": {}}}",
+            self.id,
+            self.status(),
+            string_or_null(self.answer()),
+            self.elapsed
+                .map_or(String::from("null"), |d| d.as_nanos().to_string()),
+            string_or_null(self.error()),
+        )
+    }
+
+    /// Format the record as a CSV row (see `CSV_HEADER`).
+    fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{}",
+            self.id,
+            self.status(),
+            csv_field(&self.answer().unwrap_or_default()),
+            self.elapsed
+                .map_or(String::new(), |d| d.as_nanos().to_string()),
+            csv_field(&self.error().unwrap_or_default()),
+        )
+    }
+
+    /// Format the record as a row of the text table.
+    fn to_text(&self) -> String {
+        let answer = match &self.outcome {
+            Outcome::Solved(answer) => answer.to_string(),
+            Outcome::Unimplemented => String::from("(not implemented, skipped)"),
+            Outcome::Failed(Error::Timeout(_)) => String::from("TIMEOUT"),
+            Outcome::Failed(err) => format!("error : {err}"),
+        };
+        let verdict = self.verdict.map_or(String::new(), |v| format!(" | {v}"));
+        match self.elapsed {
+            Some(d) => format!(
+                "{:>7} | {answer:<20} | {}{verdict}",
+                self.id,
+                fmt_duration(d, 12)
+            ),
+            None => format!("{:>7} | {answer}", self.id),
+        }
+    }
+}
+
+impl From<Run> for Record {
+    fn from(run: Run) -> Self {
+        let (outcome, elapsed) = match run.result {
+            Ok(answer) => (Outcome::Solved(answer), Some(run.elapsed)),
+            Err(Error::NotImplemented(_)) => (Outcome::Unimplemented, None),
+            Err(err) => (Outcome::Failed(err), Some(run.elapsed)),
+        };
+        Record {
+            id: run.id,
+            outcome,
+            elapsed,
+            verdict: None,
+        }
+    }
+}
+
+/// Quote and escape a string for JSON.
+fn json_string(s: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Quote a CSV field if needed.
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+/// Format a duration for display, padded to the given width.
+fn fmt_duration(d: Duration, width: usize) -> String {
+    format!("{:>width$}", format!("{d:?}"))
+}
+
+/// Print the totals of a batch run, in text format. `wall_time` is the time
+/// taken by the whole batch.
+fn print_summary(records: &[Record], wall_time: Duration) {
+    let count = |f: fn(&Outcome) -> bool| records.iter().filter(|r| f(&r.outcome)).count();
+    let n_solved = count(|o| matches!(o, Outcome::Solved(_)));
+    let n_failed = count(|o| matches!(o, Outcome::Failed(_)));
+    let n_skipped = count(|o| matches!(o, Outcome::Unimplemented));
+    let total: Duration = records.iter().filter_map(|r| r.elapsed).sum();
+
+    println!();
+    println!(
+        "Solved {n_solved}/{} problems in {wall_time:?}",
+        records.len()
+    );
+    println!("Sum of the time taken by each problem : {total:?}");
+    if n_failed > 0 {
+        println!("Failed : {n_failed} problem(s)");
+    }
+    if n_skipped > 0 {
+        println!("Skipped : {n_skipped} unimplemented problem(s)");
+    }
+    for verdict in [Verdict::Wrong, Verdict::Unknown] {
+        let ids: Vec<String> = records
+            .iter()
+            .filter(|r| r.verdict == Some(verdict))
+            .map(|r| format!("#{}", r.id))
+            .collect();
+        if !ids.is_empty() {
+            println!("Answers {verdict} : {}", ids.join(", "));
+        }
+    }
+
+    let mut slowest: Vec<(usize, Duration)> = records
+        .iter()
+        .filter_map(|r| r.elapsed.map(|d| (r.id, d)))
+        .collect();
+    slowest.sort_by_key(|&(_, d)| Reverse(d));
+    if !slowest.is_empty() {
+        println!("Slowest problems :");
+        for (id, d) in slowest.iter().take(N_SLOWEST) {
+            println!("    #{id:<5} {}", fmt_duration(*d, 12));
+        }
+    }
+}
+
+/// Solve all the given problems, printing one record per problem as soon as
+/// it's solved. If `verify` is set, the answers are checked against the
+/// known-correct ones. In text format, a summary is printed at the end.
+fn run(ids: &[usize], options: &Options, verify: bool) -> Vec<Record> {
+    let format = options.format;
+    match format {
+        Format::Text if verify => {
+            println!(
+                "{:>7} | {:<20} | {:>12} | Verdict",
+                "Problem", "Answer", "Time"
+            );
+            println!("{:-<7}-+-{:-<20}-+-{:-<12}-+-{:-<7}", "", "", "", "");
+        }
+        Format::Text => {
+            println!("{:>7} | {:<20} | {:>12}", "Problem", "Answer", "Time");
+            println!("{:-<7}-+-{:-<20}-+-{:-<12}", "", "", "");
+        }
+        Format::Json => println!("["),
+        Format::Csv => println!("{CSV_HEADER}"),
+    }
+
+    let mut records = Vec::new();
+    let now = Instant::now();
+    reuler::solve_each(ids, options.jobs, options.timeout, |run| {
+        let mut record = Record::from(run);
+        if verify {
+            record = record.checked();
+        }
+        match format {
+            Format::Text => println!("{}", record.to_text()),
+            Format::Json => {
+                let sep = if records.len() + 1 < ids.len() {
+                    ","
+                } else {
+                    ""
+                };
+                println!("  {}{sep}", record.to_json());
+            }
+            Format::Csv => println!("{}", record.to_csv()),
+        }
+        records.push(record);
+    });
+    let wall_time = now.elapsed();
+
+    match format {
+        Format::Text => print_summary(&records, wall_time),
+        Format::Json => println!("]"),
+        Format::Csv => (),
+    }
+    records
+}
+
+/// Exit code of the command line, given the records of the selected problems
+/// (see `USAGE`).
+fn exit_code(records: &[Record]) -> i32 {
+    let any = |f: fn(&Outcome) -> bool| records.iter().any(|r| f(&r.outcome));
+    if any(|o| matches!(o, Outcome::Failed(_))) {
+        EXIT_ERROR
+    } else if records.iter().any(|r| r.verdict == Some(Verdict::Wrong)) {
+        EXIT_WRONG
+    } else if any(|o| matches!(o, Outcome::Unimplemented)) {
+        EXIT_UNIMPLEMENTED
+    } else {
+        EXIT_OK
+    }
+}
+
+/// Benchmark all the given problems, printing one record per problem as soon
+/// as it's measured. The results are compared to the baseline and saved, if
+/// asked. Return the exit code (see `USAGE`).
+fn run_bench(ids: &[usize], options: &Options) -> i32 {
+    let baseline = options
+        .baseline
+        .as_ref()
+        .map(|path| bench::load_baseline(path).unwrap_or_else(|err| usage_error(&err)));
+
+    match options.format {
+        Format::Text => println!("{}", bench::text_header()),
+        Format::Json => println!("["),
+        Format::Csv => println!("{}", bench::CSV_HEADER),
+    }
+
+    let mut records = Vec::new();
+    for (i, &id) in ids.iter().enumerate() {
+        let record = bench::Record::bench(id, &options.bench, baseline.as_ref(), options.threshold);
+        match options.format {
+            Format::Text => println!("{}", record.to_text()),
+            Format::Json => {
+                let sep = if i + 1 < ids.len() { "," } else { "" };
+                println!("  {}{sep}", record.to_json());
+            }
+            Format::Csv => println!("{}", record.to_csv()),
+        }
+        records.push(record);
+    }
+
+    let regressions: Vec<usize> = records
+        .iter()
+        .filter(|r| r.regression)
+        .map(|r| r.id)
+        .collect();
+    match options.format {
+        Format::Text => {
+            let n_measured = records
+                .iter()
+                .filter(|r| matches!(r.outcome, bench::Outcome::Measured { .. }))
+                .count();
+            println!();
+            println!("Benchmarked {n_measured}/{} problems", records.len());
+            if !regressions.is_empty() {
+                let ids: Vec<String> = regressions.iter().map(|id| format!("#{id}")).collect();
+                println!(
+                    "Regressions (median slower by more than {}%) : {}",
+                    options.threshold,
+                    ids.join(", ")
+                );
+            }
+        }
+        Format::Json => println!("]"),
+        Format::Csv => (),
+    }
+
+    if let Some(path) = &options.save {
+        let mut results = bench::Baseline::new();
+        for r in &records {
+            if let bench::Outcome::Measured { stats, .. } = &r.outcome {
+                results.insert(r.id, stats.clone());
+            }
+        }
+        if let Err(err) = bench::save_baseline(path, &results) {
+            eprintln!("{err}");
+            return EXIT_ERROR;
+        }
+    }
+
+    let any = |f: fn(&bench::Outcome) -> bool| records.iter().any(|r| f(&r.outcome));
+    if any(|o| matches!(o, bench::Outcome::Failed(_))) {
+        EXIT_ERROR
+    } else if !regressions.is_empty() {
+        EXIT_REGRESSION
+    } else if any(|o| matches!(o, bench::Outcome::Unimplemented)) {
+        EXIT_UNIMPLEMENTED
+    } else {
+        EXIT_OK
+    }
+}
 
 fn main() {
     // Inputs
-    let args: Vec<String> = env::args().collect();
+    let args: Vec<String> = env::args().skip(1).collect();
+    let options = parse_args(&args).unwrap_or_else(|err| usage_error(&err));
+
+    let records = match options.command {
+        Command::Help => {
+            println!("{USAGE}");
+            return;
+        }
+        Command::Solve(problem_id) if problem_id < 1 => {
+            usage_error(&Error::InvalidId(problem_id).to_string())
+        }
+        Command::Solve(problem_id) if options.format == Format::Text => {
+            let record = Record::solve(problem_id.unsigned_abs(), options.timeout);
+
+            // Print the result
+            match &record.outcome {
+                Outcome::Solved(res) => {
+                    println!("Solution for problem #{problem_id} : {res}");
+                    println!("Time taken : {:?}", record.elapsed.unwrap_or_default());
+                }
+                Outcome::Unimplemented => println!("{}", Error::NotImplemented(record.id)),
+                Outcome::Failed(err) => println!("{err}"),
+            }
+            vec![record]
+        }
+        Command::Solve(problem_id) => run(&[problem_id.unsigned_abs()], &options, false),
+        Command::Run(ref ids) => run(ids, &options, false),
+        Command::Verify(ref ids) => run(ids, &options, true),
+        Command::Bench(ref ids) => process::exit(run_bench(ids, &options)),
+    };
+
+    process::exit(exit_code(&records));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
 
-    if args.len() < 2 {
-        println!("Not enough arguments. Usage : `reuler <problem_id>`");
-        process::exit(1);
+    #[test]
+    fn test_parse_selection() {
+        assert_eq!(parse_selection("3").unwrap(), vec![3]);
+        assert_eq!(parse_selection("1,5,67").unwrap(), vec![1, 5, 67]);
+        assert_eq!(parse_selection("1-4").unwrap(), vec![1, 2, 3, 4]);
+        assert_eq!(parse_selection("5-6,1,6").unwrap(), vec![5, 6, 1]);
+        assert_eq!(parse_selection("1-100000,1-100000").unwrap().len(), 100_000);
     }
 
-    let problem_id: isize = args[1].trim().parse().unwrap_or_else(|_err| {
-        println!(
-            "The given argument is not an ID (`{}`). Please provide a number.",
-            args[1]
+    #[test]
+    fn test_parse_invalid_selection() {
+        assert!(parse_selection("0").is_err());
+        assert!(parse_selection("a").is_err());
+        assert!(parse_selection("1,").is_err());
+        assert!(parse_selection("5-1").is_err());
+        assert!(parse_selection("1-2-3").is_err());
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let options = parse_args(&args("21")).unwrap();
+        assert_eq!(options.command, Command::Solve(21));
+        assert_eq!(options.format, Format::Text);
+
+        let options = parse_args(&args("run 1,2 --format json")).unwrap();
+        assert_eq!(options.command, Command::Run(vec![1, 2]));
+        assert_eq!(options.format, Format::Json);
+
+        let options = parse_args(&args("verify 1,2")).unwrap();
+        assert_eq!(options.command, Command::Verify(vec![1, 2]));
+
+        let options = parse_args(&args("--format=csv run --all")).unwrap();
+        let all_ids = reuler::problems().iter().map(|p| p.id()).collect();
+        assert_eq!(options.command, Command::Run(all_ids));
+        assert_eq!(options.format, Format::Csv);
+    }
+
+    #[test]
+    fn test_parse_bench_args() {
+        let options = parse_args(&args("bench 1-3 --runs 10 --warmup=0 --save base.csv")).unwrap();
+        assert_eq!(options.command, Command::Bench(vec![1, 2, 3]));
+        assert_eq!(options.bench.runs, Some(10));
+        assert_eq!(options.bench.warmup, 0);
+        assert_eq!(options.save, Some(String::from("base.csv")));
+        assert_eq!(options.threshold, DEFAULT_THRESHOLD);
+
+        let options = parse_args(&args("bench 1 --time 500ms --threshold 5.5")).unwrap();
+        assert_eq!(options.bench.budget, Duration::from_millis(500));
+        assert_eq!(options.threshold, 5.5);
+
+        assert!(parse_args(&args("bench 1 --runs 0")).is_err());
+        assert!(parse_args(&args("run 1 --runs 10")).is_err());
+        assert!(parse_args(&args("bench 1 --time fast")).is_err());
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("2").unwrap(), Duration::from_secs(2));
+        assert_eq!(parse_duration("1.5s").unwrap(), Duration::from_millis(1500));
+        assert_eq!(parse_duration("250ms").unwrap(), Duration::from_millis(250));
+        assert_eq!(parse_duration("1m").unwrap(), Duration::from_secs(60));
+        assert!(parse_duration("1h").is_err());
+        assert!(parse_duration("-1s").is_err());
+        assert!(parse_duration("").is_err());
+    }
+
+    #[test]
+    fn test_verify_record() {
+        let record = Record::solve(1, None).checked();
+        assert_eq!(record.verdict, Some(Verdict::Correct));
+        assert_eq!(record.status(), "correct");
+
+        let mut record = Record::solve(1, None);
+        record.outcome = Outcome::Solved(Answer::from(42_usize));
+        record.verdict = Some(Verdict::Wrong);
+        assert_eq!(exit_code(&[record]), EXIT_WRONG);
+    }
+
+    #[test]
+    fn test_timeout_record() {
+        let record = Record::from(Run {
+            id: 14,
+            result: Err(Error::Timeout(Duration::ZERO)),
+            elapsed: Duration::ZERO,
+        });
+        assert_eq!(record.status(), "timeout");
+        assert!(record.to_text().contains("TIMEOUT"));
+        assert_eq!(exit_code(&[record]), EXIT_ERROR);
+
+        let options = parse_args(&args("run 1-5 --timeout 60s")).unwrap();
+        assert_eq!(options.timeout, Some(Duration::from_secs(60)));
+        assert!(parse_args(&args("bench 1 --timeout 60s")).is_err());
+    }
+
+    #[test]
+    fn test_parse_jobs() {
+        assert_eq!(parse_args(&args("run --all")).unwrap().jobs, 1);
+        assert_eq!(parse_args(&args("run --all --jobs 4")).unwrap().jobs, 4);
+        assert_eq!(parse_args(&args("verify --jobs=0")).unwrap().jobs, 0);
+        assert!(parse_args(&args("run --all --jobs -1")).is_err());
+        assert!(parse_args(&args("bench --all --jobs 4")).is_err());
+    }
+
+    #[test]
+    fn test_parse_invalid_args() {
+        assert!(parse_args(&args("")).is_err());
+        assert!(parse_args(&args("run")).is_err());
+        assert!(parse_args(&args("run 1 2")).is_err());
+        assert!(parse_args(&args("run 1 --all")).is_err());
+        assert!(parse_args(&args("1 --format xml")).is_err());
+        assert!(parse_args(&args("1 --format")).is_err());
+        assert!(parse_args(&args("1 --verbose")).is_err());
+    }
+
+    #[test]
+    fn test_json_record() {
+        let record = Record {
+            id: 13,
+            outcome: Outcome::Solved(Answer::from("5537376230")),
+            elapsed: Some(Duration::from_nanos(1500)),
+            verdict: None,
+        };
+        assert_eq!(
+            record.to_json(),
+            "{\"id\": 13, \"status\": \"ok\", \"answer\": \"5537376230\", \"elapsed_ns\": 1500, \"error\": null}"
         );
-        process::exit(1);
-    });
 
-    // Solve the problem, timing the runtime
-    let now = time::Instant::now();
-    let res = reuler::solve(problem_id).unwrap_or_else(|err| {
-        println!("{err}");
-        process::exit(1);
-    });
-    let elapsed = now.elapsed();
+        let record = Record::solve(9999999, None);
+        assert_eq!(
+            record.to_json(),
+            "{\"id\": 9999999, \"status\": \"unimplemented\", \"answer\": null, \"elapsed_ns\": null, \"error\": null}"
+        );
+    }
 
-    // Print the result
-    println!("Solution for problem #{} : {}", problem_id, res);
-    println!("Time taken : {elapsed:?}");
+    #[test]
+    fn test_csv_record() {
+        let record = Record {
+            id: 5,
+            outcome: Outcome::Failed(Error::InvalidArgument(String::from("a \"quoted\", text"))),
+            elapsed: Some(Duration::from_nanos(42)),
+            verdict: None,
+        };
+        assert_eq!(
+            record.to_csv(),
+            "5,error,,42,\"Invalid argument : a \"\"quoted\"\", text\""
+        );
+    }
+
+    #[test]
+    fn test_json_string() {
+        assert_eq!(json_string("a\"b\\c\nd\u{1}"), "\"a\\\"b\\\\c\\nd\\u0001\"");
+    }
+
+    #[test]
+    fn test_exit_code() {
+        let record = |outcome| Record {
+            id: 1,
+            outcome,
+            elapsed: None,
+            verdict: None,
+        };
+        let solved = || record(Outcome::Solved(Answer::from(1_usize)));
+
+        assert_eq!(exit_code(&[solved(), solved()]), EXIT_OK);
+        assert_eq!(
+            exit_code(&[solved(), record(Outcome::Unimplemented)]),
+            EXIT_UNIMPLEMENTED
+        );
+        assert_eq!(
+            exit_code(&[
+                record(Outcome::Unimplemented),
+                record(Outcome::Failed(Error::Overflow(String::new())))
+            ]),
+            EXIT_ERROR
+        );
+    }
 }
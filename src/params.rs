@@ -0,0 +1,340 @@
+use crate::error::Error;
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::RangeInclusive;
+
+/// Value of a parameter given to a solver.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Integer(usize),
+    Float(f64),
+    List(Vec<usize>),
+    Text(String),
+}
+
+impl Value {
+    /// Name of the kind of value, used in error messages.
+    fn kind(&self) -> &'static str {
+        match self {
+            Value::Integer(_) => "an integer",
+            Value::Float(_) => "a float",
+            Value::List(_) => "a list of integers",
+            Value::Text(_) => "a text",
+        }
+    }
+
+    /// Check if this value can be used where `other` is expected. Integers are
+    /// accepted where floats are expected.
+    fn same_kind(&self, other: &Value) -> bool {
+        matches!(
+            (self, other),
+            (Value::Integer(_), Value::Integer(_))
+                | (Value::Integer(_), Value::Float(_))
+                | (Value::Float(_), Value::Float(_))
+                | (Value::List(_), Value::List(_))
+                | (Value::Text(_), Value::Text(_))
+        )
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Integer(x) => write!(f, "{x}"),
+            Value::Float(x) => write!(f, "{x}"),
+            Value::List(l) => {
+                let items: Vec<String> = l.iter().map(|x| x.to_string()).collect();
+                write!(f, "{}", items.join(","))
+            }
+            Value::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+impl From<usize> for Value {
+    fn from(x: usize) -> Self {
+        Value::Integer(x)
+    }
+}
+
+impl From<f64> for Value {
+    fn from(x: f64) -> Self {
+        Value::Float(x)
+    }
+}
+
+impl From<Vec<usize>> for Value {
+    fn from(l: Vec<usize>) -> Self {
+        Value::List(l)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(s: &str) -> Self {
+        Value::Text(s.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(s: String) -> Self {
+        Value::Text(s)
+    }
+}
+
+/// Declaration of a parameter accepted by a solver.
+#[derive(Debug, Clone)]
+pub struct Param {
+    /// Name of the parameter.
+    pub name: &'static str,
+    /// What the parameter is used for.
+    pub description: &'static str,
+    /// Value of the parameter in the original problem.
+    pub default: Value,
+}
+
+impl Param {
+    /// Declare a new parameter. The kind of the default value is the kind of
+    /// value expected for this parameter.
+    pub fn new(name: &'static str, description: &'static str, default: impl Into<Value>) -> Self {
+        Self {
+            name,
+            description,
+            default: default.into(),
+        }
+    }
+
+    /// Parse a value for this parameter from a string, according to the kind
+    /// of its default value. Lists are comma-separated.
+    ///
+    /// # Examples
+    /// ```
+    /// use reuler::params::{Param, Value};
+    ///
+    /// let coins = Param::new("coins", "Available coins", vec![1, 2, 5]);
+    /// assert_eq!(coins.parse("1,2,5,10").unwrap(), Value::List(vec![1, 2, 5, 10]));
+    /// assert!(coins.parse("1,two").is_err());
+    /// ```
+    pub fn parse(&self, s: &str) -> Result<Value, Error> {
+        let invalid = || {
+            Error::InvalidArgument(format!(
+                "`{s}` is not a valid value for the parameter `{}` (expected {}).",
+                self.name,
+                self.default.kind()
+            ))
+        };
+
+        match self.default {
+            Value::Integer(_) => s.trim().parse().map(Value::Integer).map_err(|_| invalid()),
+            Value::Float(_) => s.trim().parse().map(Value::Float).map_err(|_| invalid()),
+            Value::List(_) => s
+                .split(',')
+                .map(|x| x.trim().parse())
+                .collect::<Result<Vec<usize>, _>>()
+                .map(Value::List)
+                .map_err(|_| invalid()),
+            Value::Text(_) => Ok(Value::Text(s.to_string())),
+        }
+    }
+}
+
+/// Values of the parameters given to a solver.
+///
+/// # Examples
+/// ```
+/// use reuler::params::Params;
+///
+/// let params = Params::new().set("limit", 10).set("ratio", 0.5);
+///
+/// assert_eq!(params.integer("limit").unwrap(), 10);
+/// assert_eq!(params.float("ratio").unwrap(), 0.5);
+/// assert!(params.integer("ratio").is_err());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Params {
+    values: HashMap<String, Value>,
+}
+
+impl Params {
+    /// Create an empty set of parameters. Solvers will use their default
+    /// values.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the value of a parameter (builder-style).
+    pub fn set(mut self, name: &str, value: impl Into<Value>) -> Self {
+        self.insert(name, value);
+        self
+    }
+
+    /// Set the value of a parameter.
+    pub fn insert(&mut self, name: &str, value: impl Into<Value>) {
+        self.values.insert(name.to_string(), value.into());
+    }
+
+    /// Get the value of a parameter, if it was given.
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.values.get(name)
+    }
+
+    /// Check the given parameters against the declared ones, and fill the
+    /// missing parameters with their default value.
+    ///
+    /// # Errors
+    /// An error is returned if a parameter is not declared, or if its value is
+    /// not of the expected kind.
+    pub fn resolve(&self, declared: &[Param]) -> Result<Params, Error> {
+        for name in self.values.keys() {
+            if !declared.iter().any(|p| p.name == name) {
+                let names: Vec<&str> = declared.iter().map(|p| p.name).collect();
+                return Err(Error::InvalidArgument(format!(
+                    "Unknown parameter `{name}` (available parameters : [{}]).",
+                    names.join(", ")
+                )));
+            }
+        }
+
+        let mut resolved = Params::new();
+        for param in declared {
+            let value = match self.values.get(param.name) {
+                Some(v) if v.same_kind(&param.default) => v.clone(),
+                Some(v) => {
+                    return Err(Error::InvalidArgument(format!(
+                        "The parameter `{}` should be {}, not {}.",
+                        param.name,
+                        param.default.kind(),
+                        v.kind()
+                    )))
+                }
+                None => param.default.clone(),
+            };
+            resolved.insert(param.name, value);
+        }
+        Ok(resolved)
+    }
+
+    /// Get the value of an integer parameter.
+    pub fn integer(&self, name: &str) -> Result<usize, Error> {
+        match self.get(name) {
+            Some(Value::Integer(x)) => Ok(*x),
+            v => Err(Self::wrong_kind(name, "an integer", v)),
+        }
+    }
+
+    /// Get the value of an integer parameter, ensuring it's within the given
+    /// range.
+    pub fn integer_in(&self, name: &str, range: RangeInclusive<usize>) -> Result<usize, Error> {
+        let x = self.integer(name)?;
+        if !range.contains(&x) {
+            return Err(Error::InvalidArgument(format!(
+                "The parameter `{name}` should be between {} and {} (given : {x}).",
+                range.start(),
+                range.end()
+            )));
+        }
+        Ok(x)
+    }
+
+    /// Get the value of a float parameter. Integers are converted.
+    pub fn float(&self, name: &str) -> Result<f64, Error> {
+        match self.get(name) {
+            Some(Value::Float(x)) => Ok(*x),
+            Some(Value::Integer(x)) => Ok(*x as f64),
+            v => Err(Self::wrong_kind(name, "a float", v)),
+        }
+    }
+
+    /// Get the value of a list parameter.
+    pub fn list(&self, name: &str) -> Result<&[usize], Error> {
+        match self.get(name) {
+            Some(Value::List(l)) => Ok(l),
+            v => Err(Self::wrong_kind(name, "a list of integers", v)),
+        }
+    }
+
+    /// Get the value of a text parameter.
+    pub fn text(&self, name: &str) -> Result<&str, Error> {
+        match self.get(name) {
+            Some(Value::Text(s)) => Ok(s),
+            v => Err(Self::wrong_kind(name, "a text", v)),
+        }
+    }
+
+    /// Build the error returned when a parameter is missing or not of the
+    /// expected kind.
+    fn wrong_kind(name: &str, expected: &str, given: Option<&Value>) -> Error {
+        match given {
+            Some(v) => Error::InvalidArgument(format!(
+                "The parameter `{name}` should be {expected}, not {}.",
+                v.kind()
+            )),
+            None => Error::InvalidArgument(format!("The parameter `{name}` is missing.")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn declared() -> Vec<Param> {
+        vec![
+            Param::new("limit", "Upper limit", 1000),
+            Param::new("ratio", "Ratio", 0.1),
+        ]
+    }
+
+    #[test]
+    fn test_resolve_defaults() {
+        let params = Params::new().resolve(&declared()).unwrap();
+        assert_eq!(params.integer("limit").unwrap(), 1000);
+        assert_eq!(params.float("ratio").unwrap(), 0.1);
+    }
+
+    #[test]
+    fn test_resolve_override() {
+        let params = Params::new().set("limit", 10).resolve(&declared()).unwrap();
+        assert_eq!(params.integer("limit").unwrap(), 10);
+        assert_eq!(params.float("ratio").unwrap(), 0.1);
+    }
+
+    #[test]
+    fn test_resolve_integer_as_float() {
+        let params = Params::new().set("ratio", 1).resolve(&declared()).unwrap();
+        assert_eq!(params.float("ratio").unwrap(), 1.0);
+    }
+
+    #[test]
+    fn test_resolve_unknown_parameter() {
+        assert!(Params::new().set("lmit", 10).resolve(&declared()).is_err());
+    }
+
+    #[test]
+    fn test_resolve_wrong_kind() {
+        assert!(Params::new()
+            .set("limit", "ten")
+            .resolve(&declared())
+            .is_err());
+    }
+
+    #[test]
+    fn test_integer_in() {
+        let params = Params::new().set("n", 12);
+        assert_eq!(params.integer_in("n", 1..=12).unwrap(), 12);
+        assert!(params.integer_in("n", 1..=11).is_err());
+        assert!(params.integer_in("m", 1..=11).is_err());
+    }
+
+    #[test]
+    fn test_parse() {
+        let param = Param::new("limit", "Upper limit", 1000);
+        assert_eq!(param.parse("10").unwrap(), Value::Integer(10));
+        assert!(param.parse("-10").is_err());
+
+        let param = Param::new("ratio", "Ratio", 0.1);
+        assert_eq!(param.parse("0.5").unwrap(), Value::Float(0.5));
+
+        let param = Param::new("text", "Some text", "");
+        assert_eq!(param.parse("a,b").unwrap(), Value::from("a,b"));
+    }
+}
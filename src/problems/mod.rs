@@ -1,60 +1,340 @@
-pub mod prob_1;
-pub mod prob_10;
-pub mod prob_11;
-pub mod prob_12;
-pub mod prob_13;
-pub mod prob_14;
-pub mod prob_15;
-pub mod prob_16;
-pub mod prob_17;
-pub mod prob_18;
-pub mod prob_19;
-pub mod prob_2;
-pub mod prob_20;
-pub mod prob_21;
-pub mod prob_22;
-pub mod prob_23;
-pub mod prob_24;
-pub mod prob_25;
-pub mod prob_26;
-pub mod prob_27;
-pub mod prob_28;
-pub mod prob_29;
-pub mod prob_3;
-pub mod prob_30;
-pub mod prob_31;
-pub mod prob_32;
-pub mod prob_33;
-pub mod prob_34;
-pub mod prob_35;
-pub mod prob_36;
-pub mod prob_37;
-pub mod prob_38;
-pub mod prob_39;
-pub mod prob_4;
-pub mod prob_40;
-pub mod prob_41;
-pub mod prob_42;
-pub mod prob_43;
-pub mod prob_44;
-pub mod prob_45;
-pub mod prob_46;
-pub mod prob_47;
-pub mod prob_48;
-pub mod prob_49;
-pub mod prob_5;
-pub mod prob_50;
-pub mod prob_51;
-pub mod prob_52;
-pub mod prob_53;
-pub mod prob_54;
-pub mod prob_55;
-pub mod prob_56;
-pub mod prob_57;
-pub mod prob_58;
-pub mod prob_59;
-pub mod prob_6;
-pub mod prob_67;
-pub mod prob_7;
-pub mod prob_8;
-pub mod prob_9;
+//! Solutions of the problems, one module per problem.
+//!
+//! Each module exposes a `Solution` structure implementing the [`Problem`]
+//! trait. To add a new problem, create its module and add it to the list given
+//! to `register!` below : it will be declared and registered automatically.
+
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use std::fmt;
+
+/// Topics a problem is about. Useful to navigate the catalogue of problems.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tag {
+    Arithmetic,
+    BigNumbers,
+    Calendar,
+    Combinatorics,
+    Cryptography,
+    Digits,
+    Divisors,
+    DynamicProgramming,
+    Figurate,
+    Fractions,
+    Games,
+    Geometry,
+    Grids,
+    Modular,
+    Multiples,
+    Palindromes,
+    Pandigital,
+    Primes,
+    Sequences,
+    Words,
+}
+
+impl Tag {
+    /// Name of the tag, in kebab-case.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Tag::Arithmetic => "arithmetic",
+            Tag::BigNumbers => "big-numbers",
+            Tag::Calendar => "calendar",
+            Tag::Combinatorics => "combinatorics",
+            Tag::Cryptography => "cryptography",
+            Tag::Digits => "digits",
+            Tag::Divisors => "divisors",
+            Tag::DynamicProgramming => "dynamic-programming",
+            Tag::Figurate => "figurate",
+            Tag::Fractions => "fractions",
+            Tag::Games => "games",
+            Tag::Geometry => "geometry",
+            Tag::Grids => "grids",
+            Tag::Modular => "modular",
+            Tag::Multiples => "multiples",
+            Tag::Palindromes => "palindromes",
+            Tag::Pandigital => "pandigital",
+            Tag::Primes => "primes",
+            Tag::Sequences => "sequences",
+            Tag::Words => "words",
+        }
+    }
+}
+
+impl fmt::Display for Tag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Helpers from the `utils` module that a solution relies on. Useful to find
+/// worked examples of a given helper.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Technique {
+    BigInt,
+    DigitsOf,
+    DigitsOfBase,
+    DigitsToNumber,
+    Factor,
+    Fibonacci,
+    Figurate,
+    Gcf,
+    GetDivisors,
+    GetProperDivisors,
+    IsPalindrome,
+    IsPrime,
+    IsPrimeWithNeg,
+    IsSquare,
+    Isqrt,
+    ModPow,
+    PermutationsOf,
+    PrimeSum,
+    Primes,
+    Sieve,
+    Sigma,
+    SigmaSieve,
+    Tau,
+}
+
+impl Technique {
+    /// Name of the helper, as written in the `utils` module.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Technique::BigInt => "BigInt",
+            Technique::DigitsOf => "digits_of",
+            Technique::DigitsOfBase => "digits_of_base",
+            Technique::DigitsToNumber => "digits_to_number",
+            Technique::Factor => "factor",
+            Technique::Fibonacci => "Fibonacci",
+            Technique::Figurate => "figurate",
+            Technique::Gcf => "gcf",
+            Technique::GetDivisors => "get_divisors",
+            Technique::GetProperDivisors => "get_proper_divisors",
+            Technique::IsPalindrome => "is_palindrome",
+            Technique::IsPrime => "is_prime",
+            Technique::IsPrimeWithNeg => "is_prime_with_neg",
+            Technique::IsSquare => "is_square",
+            Technique::Isqrt => "isqrt",
+            Technique::ModPow => "mod_pow",
+            Technique::PermutationsOf => "permutations_of",
+            Technique::PrimeSum => "prime_sum",
+            Technique::Primes => "Primes",
+            Technique::Sieve => "Sieve",
+            Technique::Sigma => "sigma",
+            Technique::SigmaSieve => "sigma_sieve",
+            Technique::Tau => "tau",
+        }
+    }
+}
+
+impl fmt::Display for Technique {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Information describing a problem.
+#[derive(Debug)]
+pub struct Metadata {
+    /// ID of the problem, as numbered on Project Euler.
+    pub id: usize,
+    /// Title of the problem.
+    pub title: &'static str,
+    /// Difficulty rating of the problem on Project Euler, in percent.
+    pub difficulty: u8,
+    /// Topics of the problem.
+    pub tags: &'static [Tag],
+    /// Helpers from `utils` used by the solution.
+    pub techniques: &'static [Technique],
+}
+
+impl Metadata {
+    /// Link to the problem statement on Project Euler.
+    pub fn url(&self) -> String {
+        format!("https://projecteuler.net/problem={}", self.id)
+    }
+}
+
+/// Common interface of every problem solution.
+pub trait Problem: Sync {
+    /// Information describing the problem.
+    fn metadata(&self) -> &'static Metadata;
+
+    /// Compute the solution of the problem with the given parameters. The
+    /// parameters are already resolved : every declared parameter is present,
+    /// with a value of the right kind (see `solve_with()`).
+    ///
+    /// # Errors
+    /// An error is returned if a parameter has an invalid value, if the input
+    /// data of the problem is malformed, or if the computation can't be
+    /// carried out.
+    fn compute(&self, params: &Params) -> Result<Answer, Error>;
+
+    /// Parameters accepted by the solver. Their default values are the ones of
+    /// the original problem.
+    fn params(&self) -> Vec<Param> {
+        Vec::new()
+    }
+
+    /// Solve the problem and return the solution.
+    ///
+    /// # Errors
+    /// See `compute()`.
+    fn solve(&self) -> Result<Answer, Error> {
+        self.solve_with(&Params::new())
+    }
+
+    /// Solve the problem with custom parameters and return the solution.
+    /// Parameters that are not given take their default value.
+    ///
+    /// # Errors
+    /// An error is returned if a given parameter is unknown or of the wrong
+    /// kind, and for the same reasons as `compute()`.
+    fn solve_with(&self, params: &Params) -> Result<Answer, Error> {
+        let params = params.resolve(&self.params())?;
+        self.compute(&params)
+    }
+
+    /// ID of the problem, as numbered on Project Euler.
+    fn id(&self) -> usize {
+        self.metadata().id
+    }
+
+    /// Title of the problem.
+    fn title(&self) -> &'static str {
+        self.metadata().title
+    }
+}
+
+/// Declare the given problem modules, and register their `Solution` in the
+/// registry.
+macro_rules! register {
+    ($($module:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        /// Registry of all the implemented problems, sorted by ID.
+        static REGISTRY: &[&dyn Problem] = &[$(&$module::Solution),*];
+    };
+}
+
+register!(
+    prob_1, prob_2, prob_3, prob_4, prob_5, prob_6, prob_7, prob_8, prob_9, prob_10, prob_11,
+    prob_12, prob_13, prob_14, prob_15, prob_16, prob_17, prob_18, prob_19, prob_20, prob_21,
+    prob_22, prob_23, prob_24, prob_25, prob_26, prob_27, prob_28, prob_29, prob_30, prob_31,
+    prob_32, prob_33, prob_34, prob_35, prob_36, prob_37, prob_38, prob_39, prob_40, prob_41,
+    prob_42, prob_43, prob_44, prob_45, prob_46, prob_47, prob_48, prob_49, prob_50, prob_51,
+    prob_52, prob_53, prob_54, prob_55, prob_56, prob_57, prob_58, prob_59, prob_67,
+);
+
+/// Return all the problems that have an implementation, sorted by ID.
+pub fn all() -> &'static [&'static dyn Problem] {
+    REGISTRY
+}
+
+/// Find the problem with the given ID. Returns `None` if this problem doesn't
+/// have an implementation yet.
+pub fn get(id: usize) -> Option<&'static dyn Problem> {
+    REGISTRY.iter().find(|p| p.id() == id).copied()
+}
+
+/// Return all the implemented problems with the given tag, sorted by ID.
+pub fn tagged(tag: Tag) -> Vec<&'static dyn Problem> {
+    REGISTRY
+        .iter()
+        .filter(|p| p.metadata().tags.contains(&tag))
+        .copied()
+        .collect()
+}
+
+/// Return all the implemented problems whose solution uses the given helper,
+/// sorted by ID.
+pub fn using(technique: Technique) -> Vec<&'static dyn Problem> {
+    REGISTRY
+        .iter()
+        .filter(|p| p.metadata().techniques.contains(&technique))
+        .copied()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::params::Value;
+
+    #[test]
+    fn test_registry_sorted_and_unique() {
+        for pair in all().windows(2) {
+            assert!(
+                pair[0].id() < pair[1].id(),
+                "Problem #{} is registered after problem #{}",
+                pair[1].id(),
+                pair[0].id()
+            );
+        }
+    }
+
+    #[test]
+    fn test_get() {
+        assert_eq!(get(18).unwrap().title(), "Maximum Path Sum I");
+        assert!(get(0).is_none());
+        assert!(get(9999999).is_none());
+    }
+
+    #[test]
+    fn test_metadata_url() {
+        assert_eq!(
+            get(21).unwrap().metadata().url(),
+            "https://projecteuler.net/problem=21"
+        );
+    }
+
+    #[test]
+    fn test_tagged() {
+        let ids: Vec<usize> = tagged(Tag::Calendar).iter().map(|p| p.id()).collect();
+        assert_eq!(ids, vec![19]);
+
+        for p in tagged(Tag::Primes) {
+            assert!(p.metadata().tags.contains(&Tag::Primes));
+        }
+    }
+
+    #[test]
+    fn test_using() {
+        let ids: Vec<usize> = using(Technique::Fibonacci).iter().map(|p| p.id()).collect();
+        assert_eq!(ids, vec![2]);
+        assert!(using(Technique::BigInt).iter().any(|p| p.id() == 16));
+    }
+
+    #[test]
+    fn test_huge_parameters() {
+        // Parameters for which `usize::MAX` is a valid and quick input
+        let accepted = [(3, "number"), (16, "base")];
+
+        for p in all() {
+            for param in p.params() {
+                if !matches!(param.default, Value::Integer(_)) {
+                    continue;
+                }
+                let params = Params::new().set(param.name, usize::MAX);
+                let result = p.solve_with(&params);
+                if accepted.contains(&(p.id(), param.name)) {
+                    assert!(result.is_ok(), "Problem #{} : {}", p.id(), param.name);
+                } else {
+                    assert!(result.is_err(), "Problem #{} : {}", p.id(), param.name);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_every_problem_is_tagged() {
+        for p in all() {
+            assert!(
+                !p.metadata().tags.is_empty(),
+                "Problem #{} has no tag",
+                p.id()
+            );
+        }
+    }
+}
@@ -1,3 +1,8 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag};
+
 /// Compute the sum of natural numbers below the given limit that are multiple
 /// of 3 or 5.
 fn sum_multiple_3_and_5_under(limit: usize) -> usize {
@@ -10,9 +15,30 @@ fn sum_multiple_3_and_5_under(limit: usize) -> usize {
     sum
 }
 
-/// Solve the problem #1 and return the solution.
-pub fn solve() -> String {
-    sum_multiple_3_and_5_under(1000).to_string()
+/// Problem #1 : "Multiples of 3 or 5".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 1,
+            title: "Multiples of 3 or 5",
+            difficulty: 5,
+            tags: &[Tag::Multiples],
+            techniques: &[],
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("limit", "Multiples are summed below this limit", 1000),
+        ]
+    }
+
+    /// Solve the problem #1 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        Ok(sum_multiple_3_and_5_under(params.integer_in("limit", 0..=u32::MAX as usize)?).into())
+    }
 }
 
 #[cfg(test)]
@@ -1,19 +1,44 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag, Technique};
 use crate::utils;
 
 /// Compute the sum of the primes under the given limit.
-fn primes_sum(limit: usize) -> usize {
-    let primes = utils::Primes::new_up_to(limit);
-    let mut sum = 0;
+fn primes_sum(limit: usize) -> u128 {
+    utils::prime_sum(limit.saturating_sub(1))
+}
+
+/// Problem #10 : "Summation of Primes".
+pub struct Solution;
 
-    for p in primes {
-        sum += p;
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 10,
+            title: "Summation of Primes",
+            difficulty: 5,
+            tags: &[Tag::Primes],
+            techniques: &[Technique::PrimeSum],
+        }
     }
-    sum
-}
 
-/// Solve the problem #10 and return the solution.
-pub fn solve() -> String {
-    primes_sum(2000000).to_string()
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("limit", "Primes are summed below this limit", 2000000),
+        ]
+    }
+
+    /// Solve the problem #10 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        let limit = params.integer_in("limit", 0..=1_000_000_000_000)?;
+        let sum = i128::try_from(primes_sum(limit)).map_err(|_| {
+            Error::Overflow(format!(
+                "The sum of the primes below {limit} doesn't fit in an `i128`."
+            ))
+        })?;
+        Ok(Answer::Integer(sum))
+    }
 }
 
 #[cfg(test)]
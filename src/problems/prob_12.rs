@@ -1,28 +1,55 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag, Technique};
 use crate::utils;
 
 /// Compute the first triangular number with over n divisors.
-fn triangular_n_divisors(n: usize) -> usize {
-    let mut triangular = 0;
+fn triangular_n_divisors(n: usize) -> Result<usize, Error> {
+    let mut triangular: usize = 0;
     let mut i = 1;
 
     loop {
         // Compute the next triangular number
-        triangular += i;
-
-        // Find the divisors
-        let divisors = utils::get_divisors(triangular);
-
-        if divisors.len() > n {
-            return triangular;
+        triangular = triangular.checked_add(i).ok_or_else(|| {
+            Error::Overflow(format!(
+                "The first triangle number with over {n} divisors doesn't fit in a `usize`."
+            ))
+        })?;
+
+        if utils::tau(triangular) > n {
+            return Ok(triangular);
         }
 
         i += 1;
     }
 }
 
-/// Solve the problem #12 and return the solution.
-pub fn solve() -> String {
-    triangular_n_divisors(500).to_string()
+/// Problem #12 : "Highly Divisible Triangular Number".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 12,
+            title: "Highly Divisible Triangular Number",
+            difficulty: 5,
+            tags: &[Tag::Divisors, Tag::Figurate],
+            techniques: &[Technique::Tau],
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("divisors", "The triangle number should have over this number of divisors", 500),
+        ]
+    }
+
+    /// Solve the problem #12 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        // No `usize` has more than 184 320 divisors
+        Ok(triangular_n_divisors(params.integer_in("divisors", 0..=184_319)?)?.into())
+    }
 }
 
 #[cfg(test)]
@@ -31,6 +58,6 @@ mod tests {
 
     #[test]
     fn test_given_example() {
-        assert_eq!(triangular_n_divisors(5), 28);
+        assert_eq!(triangular_n_divisors(5).unwrap(), 28);
     }
 }
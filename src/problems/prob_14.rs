@@ -1,3 +1,7 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag};
 use std::collections::HashMap;
 
 /// Define a Collatz structure, that will keep intermediate results in memory,
@@ -57,9 +61,30 @@ fn longest_collatz_sequence(limit: usize) -> usize {
     curr_best_start
 }
 
-/// Solve the problem #14 and return the solution.
-pub fn solve() -> String {
-    longest_collatz_sequence(1000000).to_string()
+/// Problem #14 : "Longest Collatz Sequence".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 14,
+            title: "Longest Collatz Sequence",
+            difficulty: 5,
+            tags: &[Tag::Sequences, Tag::DynamicProgramming],
+            techniques: &[],
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("limit", "Starting numbers are taken under this limit", 1000000),
+        ]
+    }
+
+    /// Solve the problem #14 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        Ok(longest_collatz_sequence(params.integer_in("limit", 0..=10_000_000)?).into())
+    }
 }
 
 #[cfg(test)]
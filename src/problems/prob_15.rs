@@ -1,8 +1,17 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag};
+
 /// Compute the number of routes in a grid of given size, starting from top
 /// left corner, going to bottom right corner, with only right and down moves.
-fn compute_nb_lattice_paths(grid_size: usize) -> usize {
+///
+/// # Note
+/// The number of routes grows quickly : an Error is returned if it doesn't fit
+/// in a `usize` (grids bigger than 33 on a 64-bits platform).
+fn compute_nb_lattice_paths(grid_size: usize) -> Result<usize, Error> {
     // Create the map of paths, that will hold the number of paths for each point in the grid
-    let mut paths_grid = vec![vec![0; grid_size + 1]; grid_size + 1];
+    let mut paths_grid = vec![vec![0_usize; grid_size + 1]; grid_size + 1];
 
     // Initialize the starting points (top borders and left borders)
     for i in 0..grid_size + 1 {
@@ -13,17 +22,46 @@ fn compute_nb_lattice_paths(grid_size: usize) -> usize {
     // Iterate the grid from left to right, top to bottom
     for i in 1..grid_size + 1 {
         for j in 1..grid_size + 1 {
-            paths_grid[i][j] = paths_grid[i - 1][j] + paths_grid[i][j - 1];
+            paths_grid[i][j] = paths_grid[i - 1][j]
+                .checked_add(paths_grid[i][j - 1])
+                .ok_or_else(|| {
+                    Error::Overflow(format!(
+                        "The number of routes in a grid of size {grid_size} doesn't fit in a `usize`."
+                    ))
+                })?;
         }
     }
 
     // Get the accumulated number of paths from the bottom right cell
-    paths_grid[grid_size][grid_size]
+    Ok(paths_grid[grid_size][grid_size])
 }
 
-/// Solve the problem #15 and return the solution.
-pub fn solve() -> String {
-    compute_nb_lattice_paths(20).to_string()
+/// Problem #15 : "Lattice Paths".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 15,
+            title: "Lattice Paths",
+            difficulty: 5,
+            tags: &[Tag::Combinatorics, Tag::Grids, Tag::DynamicProgramming],
+            techniques: &[],
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("size", "Size of the grid", 20),
+        ]
+    }
+
+    /// Solve the problem #15 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        // There are at least 2^size routes, so bigger grids always overflow
+        let size = params.integer_in("size", 0..=usize::BITS as usize)?;
+        Ok(compute_nb_lattice_paths(size)?.into())
+    }
 }
 
 #[cfg(test)]
@@ -32,6 +70,15 @@ mod tests {
 
     #[test]
     fn test_given_example() {
-        assert_eq!(compute_nb_lattice_paths(2), 6);
+        assert_eq!(compute_nb_lattice_paths(2).unwrap(), 6);
+    }
+
+    #[test]
+    fn test_overflow() {
+        assert!(compute_nb_lattice_paths(33).is_ok());
+        assert!(matches!(
+            compute_nb_lattice_paths(34),
+            Err(Error::Overflow(_))
+        ));
     }
 }
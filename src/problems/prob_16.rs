@@ -1,21 +1,41 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag, Technique};
 use crate::utils;
 
 /// Compute the sum of digit of the number elevated to the given power.
-fn power_digit_sum(x: usize, p: usize) -> usize {
-    // The number will be big, use a BigInt
-    let mut number = utils::BigInt::from(x);
+fn power_digit_sum(x: usize, p: u32) -> usize {
+    utils::BigInt::from(x).pow(p).digits().iter().sum()
+}
+
+/// Problem #16 : "Power Digit Sum".
+pub struct Solution;
 
-    for _ in 1..p {
-        number *= x;
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 16,
+            title: "Power Digit Sum",
+            difficulty: 5,
+            tags: &[Tag::BigNumbers, Tag::Digits],
+            techniques: &[Technique::BigInt],
+        }
     }
 
-    // Sum the digits of our big number
-    number.digits.iter().sum()
-}
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("base", "Base of the power", 2),
+            Param::new("exponent", "Exponent of the power", 1000),
+        ]
+    }
 
-/// Solve the problem #16 and return the solution.
-pub fn solve() -> String {
-    power_digit_sum(2, 1000).to_string()
+    /// Solve the problem #16 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        let base = params.integer("base")?;
+        let exponent = params.integer_in("exponent", 0..=u32::MAX as usize)?;
+        Ok(power_digit_sum(base, exponent as u32).into())
+    }
 }
 
 #[cfg(test)]
@@ -1,15 +1,20 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag};
+
 /// Convert a number in its words equivalent. The second argument should always
 /// be set to `false`, it is used internally for recursive calls.
 ///
 /// # Notes
 /// This function can hundle numbers up until 999 999. Numbers above this will
 /// returns an Error.
-fn write_number_in_words(n: usize, sub: bool) -> Result<String, String> {
+fn write_number_in_words(n: usize, sub: bool) -> Result<String, Error> {
     // Return error for inputs that we can't handle
     if n >= 1000000 {
-        return Err(String::from(
+        return Err(Error::InvalidArgument(String::from(
             "`write_number_in_words()` cannot handle 1 000 000 and above.",
-        ));
+        )));
     }
 
     Ok(match n {
@@ -38,41 +43,41 @@ fn write_number_in_words(n: usize, sub: bool) -> Result<String, String> {
         // Decompose the number, and write each part to build the full number
         _ => {
             if n / 1000 > 0 {
-                let thousands = write_number_in_words(n / 1000, true).unwrap();
+                let thousands = write_number_in_words(n / 1000, true)?;
                 match n % 1000 {
                     0 => format!("{} thousand", thousands),
                     r => match r / 100 {
                         0 => format!(
                             "{} thousand and {}",
                             thousands,
-                            write_number_in_words(r, false).unwrap()
+                            write_number_in_words(r, false)?
                         ),
                         _ => format!(
                             "{} thousand {}",
                             thousands,
-                            write_number_in_words(r, false).unwrap()
+                            write_number_in_words(r, false)?
                         ),
                     },
                 }
             } else if n / 100 > 0 {
-                let hundreds = write_number_in_words(n / 100, true).unwrap();
+                let hundreds = write_number_in_words(n / 100, true)?;
                 match n % 100 {
                     0 => format!("{} hundred", hundreds),
                     r if sub => format!(
                         "{} hundred {}",
                         hundreds,
-                        write_number_in_words(r, false).unwrap()
+                        write_number_in_words(r, false)?
                     ),
                     r => format!(
                         "{} hundred and {}",
                         hundreds,
-                        write_number_in_words(r, false).unwrap()
+                        write_number_in_words(r, false)?
                     ),
                 }
             } else {
                 let remains = match n % 10 {
                     0 => String::from(""),
-                    r => format!("-{}", write_number_in_words(r, false).unwrap()),
+                    r => format!("-{}", write_number_in_words(r, false)?),
                 };
                 match n / 10 {
                     2 => format!("twenty{}", remains),
@@ -92,7 +97,7 @@ fn write_number_in_words(n: usize, sub: bool) -> Result<String, String> {
 
 /// Count the number of letters in the numbers written in words (up to a given
 /// limit).
-fn count_number_letters(limit: usize) -> Result<usize, String> {
+fn count_number_letters(limit: usize) -> Result<usize, Error> {
     let mut sum = 0;
 
     for i in 1..limit + 1 {
@@ -105,9 +110,30 @@ fn count_number_letters(limit: usize) -> Result<usize, String> {
     Ok(sum)
 }
 
-/// Solve the problem #17 and return the solution.
-pub fn solve() -> String {
-    count_number_letters(1000).unwrap().to_string()
+/// Problem #17 : "Number Letter Counts".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 17,
+            title: "Number Letter Counts",
+            difficulty: 5,
+            tags: &[Tag::Words],
+            techniques: &[],
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("limit", "Numbers are written from 1 up to this limit", 1000),
+        ]
+    }
+
+    /// Solve the problem #17 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        Ok(count_number_letters(params.integer_in("limit", 0..=999_999)?)?.into())
+    }
 }
 
 #[cfg(test)]
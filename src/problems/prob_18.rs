@@ -1,3 +1,7 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag};
 use std::cmp;
 
 pub struct Triangle {
@@ -8,26 +12,31 @@ impl Triangle {
     /// Create a Triangle from a string. The string describing the triangle
     /// should be : one row per line, the first line has a single number, and
     /// each line having one more number than the previous (separated by space)
-    pub fn new(t: &str) -> Self {
+    pub fn new(t: &str) -> Result<Self, Error> {
         let mut triangle: Vec<Vec<usize>> = Vec::new();
         for line in t.lines() {
             let mut row = Vec::new();
             for number in line.split_whitespace() {
-                let number: usize = number.parse().unwrap();
+                let number: usize = number.parse().map_err(|_| {
+                    Error::MalformedInput(format!(
+                        "`{number}` is not a valid number in the triangle."
+                    ))
+                })?;
                 row.push(number);
             }
 
             // Check that the size match
-            if triangle.len() > 0 {
-                if row.len() != triangle[triangle.len() - 1].len() + 1 {
-                    panic!("The given string does not represent a triangle. Can't construct a Triangle.")
-                }
+            let expected_len = triangle.last().map_or(1, |previous| previous.len() + 1);
+            if row.len() != expected_len {
+                return Err(Error::MalformedInput(String::from(
+                    "The given string does not represent a triangle. Can't construct a Triangle.",
+                )));
             }
 
             triangle.push(row);
         }
 
-        Triangle { rows: triangle }
+        Ok(Triangle { rows: triangle })
     }
 
     /// Find the maximum sum path from the top of the triangle to the bottom.
@@ -52,10 +61,34 @@ impl Triangle {
     }
 }
 
-/// Solve the problem #18 and return the solution.
-pub fn solve() -> String {
-    let triangle = Triangle::new("75\n95 64\n17 47 82\n18 35 87 10\n20 04 82 47 65\n19 01 23 75 03 34\n88 02 77 73 07 63 67\n99 65 04 28 06 16 70 92\n41 41 26 56 83 40 80 70 33\n41 48 72 33 47 32 37 16 94 29\n53 71 44 65 25 43 91 52 97 51 14\n70 11 33 28 77 73 17 78 39 68 17 57\n91 71 52 38 17 14 91 43 58 50 27 29 48\n63 66 04 68 89 53 67 30 73 16 69 87 40 31\n04 62 98 27 23 09 70 98 73 93 38 53 60 04 23");
-    triangle.max_path().to_string()
+/// Triangle of the problem #18.
+const TRIANGLE: &str = "75\n95 64\n17 47 82\n18 35 87 10\n20 04 82 47 65\n19 01 23 75 03 34\n88 02 77 73 07 63 67\n99 65 04 28 06 16 70 92\n41 41 26 56 83 40 80 70 33\n41 48 72 33 47 32 37 16 94 29\n53 71 44 65 25 43 91 52 97 51 14\n70 11 33 28 77 73 17 78 39 68 17 57\n91 71 52 38 17 14 91 43 58 50 27 29 48\n63 66 04 68 89 53 67 30 73 16 69 87 40 31\n04 62 98 27 23 09 70 98 73 93 38 53 60 04 23";
+
+/// Problem #18 : "Maximum Path Sum I".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 18,
+            title: "Maximum Path Sum I",
+            difficulty: 5,
+            tags: &[Tag::DynamicProgramming],
+            techniques: &[],
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("triangle", "Triangle of numbers, one row per line", TRIANGLE),
+        ]
+    }
+
+    /// Solve the problem #18 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        let triangle = Triangle::new(params.text("triangle")?)?;
+        Ok(triangle.max_path().into())
+    }
 }
 
 #[cfg(test)]
@@ -64,7 +97,18 @@ mod tests {
 
     #[test]
     fn test_given_example() {
-        let triangle = Triangle::new("3\n7 4\n2 4 6\n8 5 9 3");
+        let triangle = Triangle::new("3\n7 4\n2 4 6\n8 5 9 3").unwrap();
         assert_eq!(triangle.max_path(), 23);
     }
+
+    #[test]
+    fn test_not_a_triangle() {
+        assert!(Triangle::new("3\n7 4 2").is_err());
+        assert!(Triangle::new("3 1\n7 4 2").is_err());
+    }
+
+    #[test]
+    fn test_malformed_number() {
+        assert!(Triangle::new("3\n7 a").is_err());
+    }
 }
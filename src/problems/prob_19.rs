@@ -1,3 +1,8 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag};
+
 /// Function computing if a given year is a leap year or not.
 fn is_leap(year: usize) -> bool {
     if year % 100 == 0 {
@@ -13,12 +18,12 @@ fn is_leap(year: usize) -> bool {
 /// # Note
 /// This function does not work for any year prior 1900. It will returns an
 /// Error if you try to pass a starting year prior 1900.
-fn nb_sundays_first_of_month_between(start_year: usize, end_year: usize) -> Result<usize, String> {
+fn nb_sundays_first_of_month_between(start_year: usize, end_year: usize) -> Result<usize, Error> {
     // Handle wrong inputs
     if start_year < 1900 {
-        return Err(String::from(
+        return Err(Error::InvalidArgument(String::from(
             "Can't compute for year prior 1900. Please specify `start_year` >= 1900",
-        ));
+        )));
     }
 
     // We know the 1 january 1900 was a Monday
@@ -70,11 +75,33 @@ fn nb_sundays_first_of_month_between(start_year: usize, end_year: usize) -> Resu
     Ok(n_sundays)
 }
 
-/// Solve the problem #19 and return the solution.
-pub fn solve() -> String {
-    nb_sundays_first_of_month_between(1901, 2000)
-        .unwrap()
-        .to_string()
+/// Problem #19 : "Counting Sundays".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 19,
+            title: "Counting Sundays",
+            difficulty: 5,
+            tags: &[Tag::Calendar],
+            techniques: &[],
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("start_year", "First year considered", 1901),
+            Param::new("end_year", "Last year considered", 2000),
+        ]
+    }
+
+    /// Solve the problem #19 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        let start_year = params.integer_in("start_year", 0..=9999)?;
+        let end_year = params.integer_in("end_year", 0..=9999)?;
+        Ok(nb_sundays_first_of_month_between(start_year, end_year)?.into())
+    }
 }
 
 #[cfg(test)]
@@ -1,3 +1,7 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag, Technique};
 use crate::utils;
 
 /// Compute the sum of even values of the Fibonacci sequence below the given
@@ -20,9 +24,32 @@ fn sum_even_fibo_under(limit: usize) -> usize {
     sum
 }
 
-/// Solve the problem #2 and return the solution.
-pub fn solve() -> String {
-    sum_even_fibo_under(4000000).to_string()
+/// Problem #2 : "Even Fibonacci Numbers".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 2,
+            title: "Even Fibonacci Numbers",
+            difficulty: 5,
+            tags: &[Tag::Sequences],
+            techniques: &[Technique::Fibonacci],
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("limit", "Fibonacci terms are summed up to this limit", 4000000),
+        ]
+    }
+
+    /// Solve the problem #2 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        // The Fibonacci terms are computed two steps ahead, up to about 4.2
+        // times the limit
+        Ok(sum_even_fibo_under(params.integer_in("limit", 0..=usize::MAX / 5)?).into())
+    }
 }
 
 #[cfg(test)]
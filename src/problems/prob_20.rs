@@ -1,21 +1,38 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag, Technique};
 use crate::utils;
 
 /// Compute the sum of digits of the factorial of the given number.
 fn factorial_digit_sum(x: usize) -> usize {
-    // The number will be big, use a BigInt
-    let mut number = utils::BigInt::from(1);
+    utils::BigInt::factorial(x).digits().iter().sum()
+}
+
+/// Problem #20 : "Factorial Digit Sum".
+pub struct Solution;
 
-    for mul_factor in 2..x + 1 {
-        number *= mul_factor;
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 20,
+            title: "Factorial Digit Sum",
+            difficulty: 5,
+            tags: &[Tag::BigNumbers, Tag::Digits],
+            techniques: &[Technique::BigInt],
+        }
     }
 
-    // Sum the digits of our big number
-    number.digits.iter().sum()
-}
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("number", "Number for which the factorial is computed", 100),
+        ]
+    }
 
-/// Solve the problem #20 and return the solution.
-pub fn solve() -> String {
-    factorial_digit_sum(100).to_string()
+    /// Solve the problem #20 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        Ok(factorial_digit_sum(params.integer_in("number", 0..=1_000_000)?).into())
+    }
 }
 
 #[cfg(test)]
@@ -1,11 +1,14 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag, Technique};
 use crate::utils;
 use std::collections::HashSet;
 
 /// Compute the d() function as defined in the problem : the sum of proper
 /// divisors of n.
 fn d(n: usize) -> usize {
-    let divisors = utils::get_proper_divisors(n);
-    divisors.iter().sum()
+    utils::sigma(n, 1) - n
 }
 
 /// Compute the sum of all amicable numbers under the given limit.
@@ -20,7 +23,7 @@ fn sum_amicable_numbers_under(limit: usize) -> usize {
 
         // Otherwise, let's see if it's amicable
         let dx = d(n);
-        if dx != n && d(dx) == n {
+        if dx != n && dx > 0 && d(dx) == n {
             amicable_n.insert(n);
             amicable_n.insert(dx);
         }
@@ -28,9 +31,30 @@ fn sum_amicable_numbers_under(limit: usize) -> usize {
     amicable_n.iter().sum()
 }
 
-/// Solve the problem #21 and return the solution.
-pub fn solve() -> String {
-    sum_amicable_numbers_under(10000).to_string()
+/// Problem #21 : "Amicable Numbers".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 21,
+            title: "Amicable Numbers",
+            difficulty: 5,
+            tags: &[Tag::Divisors],
+            techniques: &[Technique::Sigma],
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("limit", "Amicable numbers are summed under this limit", 10000),
+        ]
+    }
+
+    /// Solve the problem #21 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        Ok(sum_amicable_numbers_under(params.integer_in("limit", 0..=u32::MAX as usize)?).into())
+    }
 }
 
 #[cfg(test)]
@@ -1,3 +1,8 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag};
+
 /// Compute the total score for the names given.
 fn name_score(names: &str) -> usize {
     // Parse the string
@@ -30,10 +35,30 @@ fn name_score(names: &str) -> usize {
         .sum()
 }
 
-/// Solve the problem #22 and return the solution.
-pub fn solve() -> String {
-    let names = include_str!("data/names.txt");
-    name_score(names).to_string()
+/// Problem #22 : "Names Scores".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 22,
+            title: "Names Scores",
+            difficulty: 5,
+            tags: &[Tag::Words],
+            techniques: &[],
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("names", "Comma-separated list of quoted names", include_str!("data/names.txt")),
+        ]
+    }
+
+    /// Solve the problem #22 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        Ok(name_score(params.text("names")?).into())
+    }
 }
 
 #[cfg(test)]
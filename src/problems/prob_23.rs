@@ -1,3 +1,7 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::Params;
+use crate::problems::{Metadata, Problem, Tag, Technique};
 use crate::utils;
 use std::collections::HashSet;
 
@@ -6,13 +10,8 @@ use std::collections::HashSet;
 fn non_abundant_sum() -> usize {
     // First, compute the abundant numbers until 28123 (it is proven that after
     // this limit, every numbers can be written as the sum of two abundant numbers)
-    let mut abundant_numbers = HashSet::new();
-
-    for n in 1..28123 + 1 {
-        if utils::get_proper_divisors(n).iter().sum::<usize>() > n {
-            abundant_numbers.insert(n);
-        }
-    }
+    let sigmas = utils::sigma_sieve(28123, 1);
+    let abundant_numbers: HashSet<usize> = (1..28123 + 1).filter(|&n| sigmas[n] - n > n).collect();
 
     // Then, get the list of numbers that can't be written as a sum of abundant
     // numbers
@@ -33,7 +32,22 @@ fn non_abundant_sum() -> usize {
     non_abundant_numbers.iter().sum()
 }
 
-/// Solve the problem #23 and return the solution.
-pub fn solve() -> String {
-    non_abundant_sum().to_string()
+/// Problem #23 : "Non-Abundant Sums".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 23,
+            title: "Non-Abundant Sums",
+            difficulty: 5,
+            tags: &[Tag::Divisors],
+            techniques: &[Technique::SigmaSieve],
+        }
+    }
+
+    /// Solve the problem #23 and return the solution.
+    fn compute(&self, _params: &Params) -> Result<Answer, Error> {
+        Ok(non_abundant_sum().into())
+    }
 }
@@ -1,3 +1,8 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag};
+
 /// Iterator to compute all possible permutations for a given vector of
 /// elements.
 ///
@@ -69,7 +74,7 @@ impl Iterator for Permutations {
 }
 
 /// Compute the n-th permutation for up to the given digit.
-fn n_permutation(digit_limit: usize, n: usize) -> String {
+fn n_permutation(digit_limit: usize, n: usize) -> Result<String, Error> {
     let digits = (0..digit_limit + 1)
         .map(|d| char::from_digit(d as u32, 10).unwrap())
         .collect();
@@ -77,18 +82,43 @@ fn n_permutation(digit_limit: usize, n: usize) -> String {
 
     for (i, p) in permuts.enumerate() {
         if i == n - 1 {
-            return p;
+            return Ok(p);
         }
     }
 
-    panic!(
+    Err(Error::InvalidArgument(format!(
         "The given n ({n}) is too big, there is not that much permutations. Provide a smaller n."
-    )
+    )))
 }
 
-/// Solve the problem #24 and return the solution.
-pub fn solve() -> String {
-    n_permutation(9, 1000000)
+/// Problem #24 : "Lexicographic Permutations".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 24,
+            title: "Lexicographic Permutations",
+            difficulty: 5,
+            tags: &[Tag::Combinatorics],
+            techniques: &[],
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("digits", "Digits from 0 to this digit are permuted", 9),
+            Param::new("n", "Rank of the permutation to find", 1000000),
+        ]
+    }
+
+    /// Solve the problem #24 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        let digit_limit = params.integer_in("digits", 0..=9)?;
+        let n_permutations = (1..=digit_limit + 1).product();
+        let n = params.integer_in("n", 1..=n_permutations)?;
+        Ok(n_permutation(digit_limit, n)?.into())
+    }
 }
 
 #[cfg(test)]
@@ -97,12 +127,17 @@ mod tests {
 
     #[test]
     fn test_given_example() {
-        assert_eq!(n_permutation(2, 1), String::from("012"));
-        assert_eq!(n_permutation(2, 2), String::from("021"));
-        assert_eq!(n_permutation(2, 3), String::from("102"));
-        assert_eq!(n_permutation(2, 4), String::from("120"));
-        assert_eq!(n_permutation(2, 5), String::from("201"));
-        assert_eq!(n_permutation(2, 6), String::from("210"));
+        assert_eq!(n_permutation(2, 1).unwrap(), String::from("012"));
+        assert_eq!(n_permutation(2, 2).unwrap(), String::from("021"));
+        assert_eq!(n_permutation(2, 3).unwrap(), String::from("102"));
+        assert_eq!(n_permutation(2, 4).unwrap(), String::from("120"));
+        assert_eq!(n_permutation(2, 5).unwrap(), String::from("201"));
+        assert_eq!(n_permutation(2, 6).unwrap(), String::from("210"));
+    }
+
+    #[test]
+    fn test_n_too_big() {
+        assert!(n_permutation(2, 7).is_err());
     }
 
     #[test]
@@ -1,28 +1,55 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag, Technique};
 use crate::utils;
 
 /// Compute the index of the first Fibonacci term with n digits.
-fn n_digit_fibonacci(n: usize) -> usize {
+fn n_digit_fibonacci(n: usize) -> Result<usize, Error> {
     if n == 0 {
-        panic!("A number can't have 0 digits !");
+        return Err(Error::InvalidArgument(String::from(
+            "A number can't have 0 digits !",
+        )));
     }
 
     let mut curr = utils::BigInt::from(1);
     let mut next = utils::BigInt::from(1);
     let mut i = 1;
 
-    while curr.to_string().len() < n {
+    while curr.num_digits() < n {
         let next_next = &curr + &next;
         curr = next;
         next = next_next;
 
         i += 1;
     }
-    i
+    Ok(i)
 }
 
-/// Solve the problem #25 and return the solution.
-pub fn solve() -> String {
-    n_digit_fibonacci(1000).to_string()
+/// Problem #25 : "1000-digit Fibonacci Number".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 25,
+            title: "1000-digit Fibonacci Number",
+            difficulty: 5,
+            tags: &[Tag::BigNumbers, Tag::Sequences],
+            techniques: &[Technique::BigInt],
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("digits", "Number of digits of the Fibonacci term", 1000),
+        ]
+    }
+
+    /// Solve the problem #25 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        Ok(n_digit_fibonacci(params.integer_in("digits", 1..=100_000)?)?.into())
+    }
 }
 
 #[cfg(test)]
@@ -31,17 +58,16 @@ mod tests {
 
     #[test]
     fn test_given_example() {
-        assert_eq!(n_digit_fibonacci(3), 12);
+        assert_eq!(n_digit_fibonacci(3).unwrap(), 12);
     }
 
     #[test]
-    #[should_panic]
     fn test_error() {
-        assert_eq!(n_digit_fibonacci(0), 12);
+        assert!(n_digit_fibonacci(0).is_err());
     }
 
     #[test]
     fn test_base_case() {
-        assert_eq!(n_digit_fibonacci(1), 1);
+        assert_eq!(n_digit_fibonacci(1).unwrap(), 1);
     }
 }
@@ -1,3 +1,7 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag};
 use std::collections::HashSet;
 
 /// Compute the size of the decimal cycle of the fraction 1/n.
@@ -50,9 +54,30 @@ fn max_cycle_size_until(max: usize) -> usize {
     cycle_max_n
 }
 
-/// Solve the problem #26 and return the solution.
-pub fn solve() -> String {
-    max_cycle_size_until(1000).to_string()
+/// Problem #26 : "Reciprocal Cycles".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 26,
+            title: "Reciprocal Cycles",
+            difficulty: 5,
+            tags: &[Tag::Fractions],
+            techniques: &[],
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("limit", "Denominators are taken up to this limit", 1000),
+        ]
+    }
+
+    /// Solve the problem #26 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        Ok(max_cycle_size_until(params.integer_in("limit", 0..=10_000_000)?).into())
+    }
 }
 
 #[cfg(test)]
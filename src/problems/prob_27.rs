@@ -1,3 +1,7 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag, Technique};
 use crate::utils;
 use std::collections::HashMap;
 
@@ -49,9 +53,31 @@ fn max_quadratic_formula(limit: usize) -> isize {
     best_a * best_b
 }
 
-/// Solve the problem #27 and return the solution.
-pub fn solve() -> String {
-    max_quadratic_formula(1000).to_string()
+/// Problem #27 : "Quadratic Primes".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 27,
+            title: "Quadratic Primes",
+            difficulty: 5,
+            tags: &[Tag::Primes],
+            techniques: &[Technique::IsPrimeWithNeg],
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("limit", "Coefficients are taken between -limit and limit", 1000),
+        ]
+    }
+
+    /// Solve the problem #27 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        let limit = params.integer_in("limit", 0..=isize::MAX as usize)?;
+        Ok(max_quadratic_formula(limit).into())
+    }
 }
 
 #[cfg(test)]
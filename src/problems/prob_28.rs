@@ -1,3 +1,8 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag};
+
 /// Compute the sum of the numbers located on the diagonals of the spiral of
 /// given size.
 fn sum_spiral_diagonals(size: usize) -> usize {
@@ -15,9 +20,37 @@ fn sum_spiral_diagonals(size: usize) -> usize {
     sum
 }
 
-/// Solve the problem #28 and return the solution.
-pub fn solve() -> String {
-    sum_spiral_diagonals(1001).to_string()
+/// Problem #28 : "Number Spiral Diagonals".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 28,
+            title: "Number Spiral Diagonals",
+            difficulty: 5,
+            tags: &[Tag::Grids],
+            techniques: &[],
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("size", "Size of the side of the spiral (odd number)", 1001),
+        ]
+    }
+
+    /// Solve the problem #28 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        // Above, the sum of the diagonals doesn't fit in a `usize`
+        let size = params.integer_in("size", 1..=3_024_615)?;
+        if size % 2 == 0 {
+            return Err(Error::InvalidArgument(format!(
+                "The size of the spiral should be an odd number (given : {size})."
+            )));
+        }
+        Ok(sum_spiral_diagonals(size).into())
+    }
 }
 
 #[cfg(test)]
@@ -28,4 +61,10 @@ mod tests {
     fn test_given_example() {
         assert_eq!(sum_spiral_diagonals(5), 101);
     }
+
+    #[test]
+    fn test_even_size() {
+        let params = Params::new().set("size", 4);
+        assert!(Solution.solve_with(&params).is_err());
+    }
 }
@@ -1,3 +1,7 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag, Technique};
 use crate::utils;
 use std::collections::HashSet;
 
@@ -7,25 +11,43 @@ fn distinct_powers(min: usize, max: usize) -> usize {
     let mut distincts = HashSet::new();
 
     for a in min..max + 1 {
-        // First, compute the starting point
-        let mut start = a;
-        for _ in 1..min - 1 {
-            start *= a;
-        }
-
-        // Then compute the numbers
-        let mut curr = utils::BigInt::from(start);
+        // First, compute the starting point, then the numbers
+        let mut curr = utils::BigInt::from(a).pow(min as u32 - 1);
         for _b in min..max + 1 {
             curr *= a;
-            distincts.insert(curr.digits.clone());
+            distincts.insert(curr.clone());
         }
     }
     distincts.len()
 }
 
-/// Solve the problem #29 and return the solution.
-pub fn solve() -> String {
-    distinct_powers(2, 100).to_string()
+/// Problem #29 : "Distinct Powers".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 29,
+            title: "Distinct Powers",
+            difficulty: 5,
+            tags: &[Tag::BigNumbers],
+            techniques: &[Technique::BigInt],
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("min", "Minimum value of a and b", 2),
+            Param::new("max", "Maximum value of a and b", 100),
+        ]
+    }
+
+    /// Solve the problem #29 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        let max = params.integer_in("max", 2..=1000)?;
+        let min = params.integer_in("min", 2..=max)?;
+        Ok(distinct_powers(min, max).into())
+    }
 }
 
 #[cfg(test)]
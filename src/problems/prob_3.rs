@@ -1,25 +1,38 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag, Technique};
+use crate::utils;
+
 /// Compute the largest prime factor of the given number.
 fn largest_prime_factor(x: usize) -> usize {
-    let mut n = x;
-    let mut f = 2;
+    utils::factor(x).last().map_or(1, |&(p, _)| p)
+}
 
-    while f * f <= n {
-        if n % f == 0 {
-            n /= f;
-        } else {
-            if f == 2 {
-                f += 1;
-            } else {
-                f += 2;
-            }
+/// Problem #3 : "Largest Prime Factor".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 3,
+            title: "Largest Prime Factor",
+            difficulty: 5,
+            tags: &[Tag::Primes],
+            techniques: &[Technique::Factor],
         }
     }
-    n
-}
 
-/// Solve the problem #3 and return the solution.
-pub fn solve() -> String {
-    largest_prime_factor(600851475143).to_string()
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("number", "Number to factorize", 600851475143),
+        ]
+    }
+
+    /// Solve the problem #3 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        Ok(largest_prime_factor(params.integer_in("number", 1..=usize::MAX)?).into())
+    }
 }
 
 #[cfg(test)]
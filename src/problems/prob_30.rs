@@ -1,3 +1,8 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag};
+
 /// Compute the sum of the n-special numbers, where a n-special number is a
 /// number that can be written as the sum of the n-th power of its digit.
 fn sum_power_of_digits_nth(n: u32) -> usize {
@@ -30,9 +35,31 @@ fn sum_power_of_digits_nth(n: u32) -> usize {
     sum
 }
 
-/// Solve the problem #30 and return the solution.
-pub fn solve() -> String {
-    sum_power_of_digits_nth(5).to_string()
+/// Problem #30 : "Digit Fifth Powers".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 30,
+            title: "Digit Fifth Powers",
+            difficulty: 5,
+            tags: &[Tag::Digits],
+            techniques: &[],
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("power", "Power applied to the digits", 5),
+        ]
+    }
+
+    /// Solve the problem #30 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        let power = params.integer_in("power", 1..=19)? as u32;
+        Ok(sum_power_of_digits_nth(power).into())
+    }
 }
 
 #[cfg(test)]
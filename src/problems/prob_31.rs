@@ -1,3 +1,8 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag};
+
 /// Compute the number of possible ways to make a specific amount (goal) with
 /// the given coins. This function assumes coins are sorted.
 fn coin_ways(coins: &Vec<usize>, goal: usize) -> usize {
@@ -16,16 +21,39 @@ fn coin_ways(coins: &Vec<usize>, goal: usize) -> usize {
     n_ways
 }
 
-/// Compute the number of possible ways to make a specific amount (goal) with
-/// the UK coins : 1p, 2p, 5p, 10p, 20p, 50p, £1 (100p), and £2 (200p).
-fn coin_ways_uk(goal: usize) -> usize {
-    let coins = vec![1, 2, 5, 10, 20, 50, 100, 200];
-    coin_ways(&coins, goal)
-}
+/// Problem #31 : "Coin Sums".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 31,
+            title: "Coin Sums",
+            difficulty: 5,
+            tags: &[Tag::Combinatorics],
+            techniques: &[],
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("goal", "Amount to make, in pence", 200),
+            Param::new("coins", "Available coins, in pence", vec![1, 2, 5, 10, 20, 50, 100, 200]),
+        ]
+    }
 
-/// Solve the problem #31 and return the solution.
-pub fn solve() -> String {
-    coin_ways_uk(200).to_string()
+    /// Solve the problem #31 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        let mut coins = params.list("coins")?.to_vec();
+        if coins.contains(&0) {
+            return Err(Error::InvalidArgument(String::from(
+                "Coins should have a positive value.",
+            )));
+        }
+        coins.sort();
+        // The recursion goes as deep as the goal
+        Ok(coin_ways(&coins, params.integer_in("goal", 0..=1000)?).into())
+    }
 }
 
 #[cfg(test)]
@@ -37,4 +65,16 @@ mod tests {
         let coins = vec![1, 2, 5];
         assert_eq!(coin_ways(&coins, 6), 5);
     }
+
+    #[test]
+    fn test_unsorted_coins() {
+        let params = Params::new().set("coins", vec![5, 1, 2]).set("goal", 6);
+        assert_eq!(Solution.solve_with(&params), Ok(Answer::Integer(5)));
+    }
+
+    #[test]
+    fn test_zero_coin() {
+        let params = Params::new().set("coins", vec![0, 1]);
+        assert!(Solution.solve_with(&params).is_err());
+    }
 }
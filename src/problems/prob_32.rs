@@ -1,3 +1,7 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::Params;
+use crate::problems::{Metadata, Problem, Tag};
 use std::collections::HashSet;
 
 /// Check if a product of a * b is pandigital or not.
@@ -54,9 +58,24 @@ fn sum_pandigital_products() -> usize {
     pandigital_products.iter().sum()
 }
 
-/// Solve the problem #32 and return the solution.
-pub fn solve() -> String {
-    sum_pandigital_products().to_string()
+/// Problem #32 : "Pandigital Products".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 32,
+            title: "Pandigital Products",
+            difficulty: 5,
+            tags: &[Tag::Pandigital],
+            techniques: &[],
+        }
+    }
+
+    /// Solve the problem #32 and return the solution.
+    fn compute(&self, _params: &Params) -> Result<Answer, Error> {
+        Ok(sum_pandigital_products().into())
+    }
 }
 
 #[cfg(test)]
@@ -1,3 +1,7 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::Params;
+use crate::problems::{Metadata, Problem, Tag, Technique};
 use crate::utils;
 
 /// Check if a fraction is a non-trivial digit canceling fraction. This
@@ -56,9 +60,24 @@ fn denominator_of_product_digit_cancelling_fractions() -> usize {
     prod_denom / gcf
 }
 
-/// Solve the problem #33 and return the solution.
-pub fn solve() -> String {
-    denominator_of_product_digit_cancelling_fractions().to_string()
+/// Problem #33 : "Digit Cancelling Fractions".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 33,
+            title: "Digit Cancelling Fractions",
+            difficulty: 5,
+            tags: &[Tag::Fractions, Tag::Digits],
+            techniques: &[Technique::Gcf],
+        }
+    }
+
+    /// Solve the problem #33 and return the solution.
+    fn compute(&self, _params: &Params) -> Result<Answer, Error> {
+        Ok(denominator_of_product_digit_cancelling_fractions().into())
+    }
 }
 
 #[cfg(test)]
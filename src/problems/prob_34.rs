@@ -1,3 +1,8 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::Params;
+use crate::problems::{Metadata, Problem, Tag};
+
 /// Compute the sum of the numbers which are equal to the sum of the factorial
 /// of their digits.
 fn sum_digit_factorials() -> usize {
@@ -19,8 +24,6 @@ fn sum_digit_factorials() -> usize {
         max_n_digits += 1;
     }
 
-    println!("{max_n_digits}");
-
     // Compute the sum
     let mut sum = 0;
     for n in 10..10_usize.pow(max_n_digits) {
@@ -38,7 +41,22 @@ fn sum_digit_factorials() -> usize {
     sum
 }
 
-/// Solve the problem #34 and return the solution.
-pub fn solve() -> String {
-    sum_digit_factorials().to_string()
+/// Problem #34 : "Digit Factorials".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 34,
+            title: "Digit Factorials",
+            difficulty: 5,
+            tags: &[Tag::Digits],
+            techniques: &[],
+        }
+    }
+
+    /// Solve the problem #34 and return the solution.
+    fn compute(&self, _params: &Params) -> Result<Answer, Error> {
+        Ok(sum_digit_factorials().into())
+    }
 }
@@ -1,6 +1,12 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag, Technique};
 use crate::utils;
 
-fn is_circular_prime(x: usize) -> bool {
+/// Check if the given number is a circular prime. The sieve should cover all
+/// the numbers with as many digits as the given number.
+fn is_circular_prime(x: usize, sieve: &utils::Sieve) -> bool {
     // First, get the digits of the number
     let mut digits = utils::digits_of(x);
 
@@ -22,7 +28,7 @@ fn is_circular_prime(x: usize) -> bool {
         }
 
         // Check if it's prime
-        if !utils::is_prime(n) {
+        if !sieve.is_prime(n) {
             return false;
         }
 
@@ -35,19 +41,47 @@ fn is_circular_prime(x: usize) -> bool {
 /// Compute the number of circular prime numbers under a given limit.
 fn n_circular_prime_below(limit: usize) -> usize {
     let mut n_circular_primes = 0;
-    let primes = utils::Primes::new_up_to(limit);
 
-    for p in primes {
-        if is_circular_prime(p) {
+    // Rotations of a number can be above the limit, but keep the same number
+    // of digits
+    let mut sieve_limit = 1;
+    while sieve_limit < limit {
+        sieve_limit *= 10;
+    }
+    let sieve = utils::Sieve::new(sieve_limit);
+
+    for p in sieve.iter().take_while(|&p| p < limit) {
+        if is_circular_prime(p, &sieve) {
             n_circular_primes += 1;
         }
     }
     n_circular_primes
 }
 
-/// Solve the problem #35 and return the solution.
-pub fn solve() -> String {
-    n_circular_prime_below(1000000).to_string()
+/// Problem #35 : "Circular Primes".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 35,
+            title: "Circular Primes",
+            difficulty: 5,
+            tags: &[Tag::Primes, Tag::Digits],
+            techniques: &[Technique::Sieve, Technique::DigitsOf],
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("limit", "Circular primes are counted below this limit", 1000000),
+        ]
+    }
+
+    /// Solve the problem #35 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        Ok(n_circular_prime_below(params.integer_in("limit", 0..=1_000_000_000)?).into())
+    }
 }
 
 #[cfg(test)]
@@ -61,16 +95,16 @@ mod tests {
 
     #[test]
     fn test_is_circular_prime_true() {
-        assert!(is_circular_prime(17));
+        assert!(is_circular_prime(17, &utils::Sieve::new(100)));
     }
 
     #[test]
     fn test_is_circular_prime_false() {
-        assert!(!is_circular_prime(19));
+        assert!(!is_circular_prime(19, &utils::Sieve::new(100)));
     }
 
     #[test]
     fn test_is_circular_prime_not_prime() {
-        assert!(!is_circular_prime(54));
+        assert!(!is_circular_prime(54, &utils::Sieve::new(100)));
     }
 }
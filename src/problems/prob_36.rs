@@ -1,3 +1,7 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag, Technique};
 use crate::utils;
 
 /// Check if a given number is a double palindrome
@@ -32,9 +36,30 @@ fn sum_double_base_palindromes(limit: usize) -> usize {
     sum
 }
 
-/// Solve the problem #36 and return the solution.
-pub fn solve() -> String {
-    sum_double_base_palindromes(1000000).to_string()
+/// Problem #36 : "Double-base Palindromes".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 36,
+            title: "Double-base Palindromes",
+            difficulty: 5,
+            tags: &[Tag::Palindromes, Tag::Digits],
+            techniques: &[Technique::DigitsOfBase, Technique::IsPalindrome],
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("limit", "Palindromes are summed below this limit", 1000000),
+        ]
+    }
+
+    /// Solve the problem #36 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        Ok(sum_double_base_palindromes(params.integer_in("limit", 0..=1_000_000_000)?).into())
+    }
 }
 
 #[cfg(test)]
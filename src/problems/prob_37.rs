@@ -1,3 +1,7 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::Params;
+use crate::problems::{Metadata, Problem, Tag, Technique};
 use crate::utils;
 
 /// Compute the sum of all truncatable primes.
@@ -5,14 +9,20 @@ fn sum_truncatable_primes() -> usize {
     let mut sum = 0;
     let mut n_truncatable_primes = 0;
     let mut curr_primes = vec![2, 3, 5, 7];
+    let mut sieve = utils::Sieve::new(1000);
 
     // Iteratively add more digits to the right to build primes with x digits
     'inf: loop {
+        // Each step adds one digit : grow the sieve accordingly
+        if curr_primes.iter().any(|p| p * 10 + 9 > sieve.limit()) {
+            sieve = utils::Sieve::new(sieve.limit() * 10);
+        }
+
         let mut next_primes = Vec::new();
         for p in curr_primes.iter() {
             for i in [1, 3, 5, 7, 9] {
                 let next_p = p * 10 + i;
-                if utils::is_prime(next_p) {
+                if sieve.is_prime(next_p) {
                     next_primes.push(next_p);
                 }
             }
@@ -24,7 +34,7 @@ fn sum_truncatable_primes() -> usize {
             let mut is_left_truncatable = true;
             let mut m = 10;
             while m < *p {
-                if !utils::is_prime(p % m) {
+                if !sieve.is_prime(p % m) {
                     is_left_truncatable = false;
                     break;
                 }
@@ -46,7 +56,22 @@ fn sum_truncatable_primes() -> usize {
     sum
 }
 
-/// Solve the problem #37 and return the solution.
-pub fn solve() -> String {
-    sum_truncatable_primes().to_string()
+/// Problem #37 : "Truncatable Primes".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 37,
+            title: "Truncatable Primes",
+            difficulty: 5,
+            tags: &[Tag::Primes, Tag::Digits],
+            techniques: &[Technique::Sieve],
+        }
+    }
+
+    /// Solve the problem #37 and return the solution.
+    fn compute(&self, _params: &Params) -> Result<Answer, Error> {
+        Ok(sum_truncatable_primes().into())
+    }
 }
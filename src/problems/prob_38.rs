@@ -1,3 +1,7 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::Params;
+use crate::problems::{Metadata, Problem, Tag};
 use std::collections::HashSet;
 
 /// Compute the largest pandigital 9-digit number that can be formed as the
@@ -35,7 +39,22 @@ fn largest_pandigital_concatenated_product() -> usize {
     best_pandigital
 }
 
-/// Solve the problem #38 and return the solution.
-pub fn solve() -> String {
-    largest_pandigital_concatenated_product().to_string()
+/// Problem #38 : "Pandigital Multiples".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 38,
+            title: "Pandigital Multiples",
+            difficulty: 5,
+            tags: &[Tag::Pandigital],
+            techniques: &[],
+        }
+    }
+
+    /// Solve the problem #38 and return the solution.
+    fn compute(&self, _params: &Params) -> Result<Answer, Error> {
+        Ok(largest_pandigital_concatenated_product().into())
+    }
 }
@@ -1,3 +1,7 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag};
 use std::collections::HashMap;
 
 /// Compute the p (under a given limit) that maximizes the number of right
@@ -30,9 +34,30 @@ fn which_p_maximize_n_triangle(limit: usize) -> usize {
         .0
 }
 
-/// Solve the problem #39 and return the solution.
-pub fn solve() -> String {
-    which_p_maximize_n_triangle(1000).to_string()
+/// Problem #39 : "Integer Right Triangles".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 39,
+            title: "Integer Right Triangles",
+            difficulty: 5,
+            tags: &[Tag::Geometry],
+            techniques: &[],
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("limit", "Maximum perimeter of the triangles", 1000),
+        ]
+    }
+
+    /// Solve the problem #39 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        Ok(which_p_maximize_n_triangle(params.integer_in("limit", 0..=u32::MAX as usize)?).into())
+    }
 }
 
 #[cfg(test)]
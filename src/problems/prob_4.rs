@@ -1,3 +1,8 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag};
+
 /// Compute the largest palindrome made of the product of x digits, where x is
 /// given.
 fn largest_palindrome_product(n_digits: u32) -> usize {
@@ -35,9 +40,31 @@ fn is_palindromic(x: usize) -> bool {
     s1 == s2
 }
 
-/// Solve the problem #4 and return the solution.
-pub fn solve() -> String {
-    largest_palindrome_product(3).to_string()
+/// Problem #4 : "Largest Palindrome Product".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 4,
+            title: "Largest Palindrome Product",
+            difficulty: 5,
+            tags: &[Tag::Palindromes],
+            techniques: &[],
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("digits", "Number of digits of the two factors", 3),
+        ]
+    }
+
+    /// Solve the problem #4 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        let n_digits = params.integer_in("digits", 1..=9)? as u32;
+        Ok(largest_palindrome_product(n_digits).into())
+    }
 }
 
 #[cfg(test)]
@@ -1,3 +1,7 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag, Technique};
 use crate::utils;
 
 /// If we write positive integers next to each other, we can count the x-th
@@ -35,9 +39,30 @@ fn champernowne_constant(order: usize) -> usize {
     prod_result
 }
 
-/// Solve the problem #40 and return the solution.
-pub fn solve() -> String {
-    champernowne_constant(6).to_string()
+/// Problem #40 : "Champernowne's Constant".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 40,
+            title: "Champernowne's Constant",
+            difficulty: 5,
+            tags: &[Tag::Digits],
+            techniques: &[Technique::DigitsOf],
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("order", "Digits #1, #10, ... up to #10^order are multiplied", 6),
+        ]
+    }
+
+    /// Solve the problem #40 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        Ok(champernowne_constant(params.integer_in("order", 0..=17)?).into())
+    }
 }
 
 #[cfg(test)]
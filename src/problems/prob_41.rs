@@ -1,3 +1,7 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::Params;
+use crate::problems::{Metadata, Problem, Tag, Technique};
 use crate::utils;
 
 /// Compute the largest n-digit pandigital number that is also prime.
@@ -55,7 +59,22 @@ fn largest_pandigital_prime() -> usize {
     largest_number
 }
 
-/// Solve the problem #41 and return the solution.
-pub fn solve() -> String {
-    largest_pandigital_prime().to_string()
+/// Problem #41 : "Pandigital Prime".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 41,
+            title: "Pandigital Prime",
+            difficulty: 5,
+            tags: &[Tag::Pandigital, Tag::Primes],
+            techniques: &[Technique::IsPrime, Technique::PermutationsOf, Technique::DigitsToNumber],
+        }
+    }
+
+    /// Solve the problem #41 and return the solution.
+    fn compute(&self, _params: &Params) -> Result<Answer, Error> {
+        Ok(largest_pandigital_prime().into())
+    }
 }
@@ -1,10 +1,13 @@
-use std::collections::HashSet;
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag, Technique};
+use crate::utils::figurate;
 
 /// Compute the number of triangle words among the given list of words.
 fn number_of_triangle_words(words: &str) -> usize {
     // For each word, compute the word value
     let mut word_values = Vec::new();
-    let mut max_word_value = 0;
     for word in words.split(",") {
         let mut word_val = 0;
         for c in word.chars() {
@@ -16,37 +19,39 @@ fn number_of_triangle_words(words: &str) -> usize {
             word_val += (c as usize) - ('A' as usize) + 1;
         }
         word_values.push(word_val);
-
-        // Keep track of the max word value, to know until where we have to compute triangle numbers
-        if word_val > max_word_value {
-            max_word_value = word_val;
-        }
-    }
-
-    // Compute all triangle numbers up until we cover all words
-    let mut triangle_numbers = HashSet::new();
-    let mut n = 1;
-    loop {
-        let t = n * (n + 1) / 2;
-        triangle_numbers.insert(t);
-
-        if t >= max_word_value {
-            break;
-        }
-        n += 1;
     }
 
     // Finally, count the number of words that are triangle numbers
     word_values
         .iter()
-        .map(|v| triangle_numbers.contains(&v) as usize)
-        .sum()
+        .filter(|&&v| figurate::is_triangle(v))
+        .count()
 }
 
-/// Solve the problem #42 and return the solution.
-pub fn solve() -> String {
-    let words = include_str!("data/words.txt");
-    number_of_triangle_words(words).to_string()
+/// Problem #42 : "Coded Triangle Numbers".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 42,
+            title: "Coded Triangle Numbers",
+            difficulty: 5,
+            tags: &[Tag::Words, Tag::Figurate],
+            techniques: &[Technique::Figurate],
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("words", "Comma-separated list of quoted words", include_str!("data/words.txt")),
+        ]
+    }
+
+    /// Solve the problem #42 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        Ok(number_of_triangle_words(params.text("words")?).into())
+    }
 }
 
 #[cfg(test)]
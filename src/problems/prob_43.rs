@@ -1,3 +1,7 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::Params;
+use crate::problems::{Metadata, Problem, Tag, Technique};
 use crate::utils;
 
 /// Compute the sum of all 0-9 pandigital numbers such that :
@@ -28,7 +32,22 @@ fn divisible_pandigital() -> usize {
     sum
 }
 
-/// Solve the problem #43 and return the solution.
-pub fn solve() -> String {
-    divisible_pandigital().to_string()
+/// Problem #43 : "Sub-string Divisibility".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 43,
+            title: "Sub-string Divisibility",
+            difficulty: 5,
+            tags: &[Tag::Pandigital, Tag::Multiples],
+            techniques: &[Technique::PermutationsOf, Technique::DigitsToNumber],
+        }
+    }
+
+    /// Solve the problem #43 and return the solution.
+    fn compute(&self, _params: &Params) -> Result<Answer, Error> {
+        Ok(divisible_pandigital().into())
+    }
 }
@@ -1,8 +1,14 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::Params;
+use crate::problems::{Metadata, Problem, Tag, Technique};
+use crate::utils;
+
 /// Find the smallest odd composite number that cannot be written as the sum of
 /// a prime and twice a square.
 fn goldbach_conjecture_counter_example() -> usize {
     let mut previous_primes = vec![2, 3, 5, 7];
-    let mut x = 7;
+    let mut x: usize = 7;
 
     loop {
         // Skip even-numbers
@@ -28,9 +34,7 @@ fn goldbach_conjecture_counter_example() -> usize {
         // as a prime + twice a square
         let mut conjecture_is_right = false;
         for pp in previous_primes.iter().rev() {
-            let square = (x - pp) / 2;
-            let s = (square as f64).sqrt() as usize;
-            if 2 * s * s + pp == x {
+            if (x - pp).is_multiple_of(2) && utils::is_square((x - pp) / 2) {
                 // The conjecture holds...
                 conjecture_is_right = true;
                 break;
@@ -44,7 +48,22 @@ fn goldbach_conjecture_counter_example() -> usize {
     }
 }
 
-/// Solve the problem #46 and return the solution.
-pub fn solve() -> String {
-    goldbach_conjecture_counter_example().to_string()
+/// Problem #46 : "Goldbach's Other Conjecture".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 46,
+            title: "Goldbach's Other Conjecture",
+            difficulty: 5,
+            tags: &[Tag::Primes],
+            techniques: &[Technique::IsSquare],
+        }
+    }
+
+    /// Solve the problem #46 and return the solution.
+    fn compute(&self, _params: &Params) -> Result<Answer, Error> {
+        Ok(goldbach_conjecture_counter_example().into())
+    }
 }
@@ -1,72 +1,49 @@
-use std::collections::HashMap;
-use std::collections::HashSet;
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag, Technique};
+use crate::utils;
 
 /// Find the first n consecutive numbers that have n distinct prime factors.
 fn distinct_prime_factors(n: usize) -> usize {
-    let mut previous_primes = Vec::new();
-    let mut x = 2;
-    let mut prime_factors = HashMap::new();
-    prime_factors.insert(1, HashSet::new());
-
-    loop {
-        // First, find the first factor among the previous primes
-        let mut first_factor = None;
-        for pp in previous_primes.iter() {
-            if x % pp == 0 {
-                first_factor = Some(pp);
-                break;
-            }
-        }
-
-        // Then find the other factors from our cache
-        let factors = match first_factor {
-            Some(ff) => {
-                // Reduce x with the first factor
-                let mut x_reduced = x / ff;
-                while x_reduced % ff == 0 {
-                    x_reduced /= ff;
-                }
-
-                // Find the prime factors of the reduced x and add it our first factor
-                let mut factors = prime_factors.get(&x_reduced).unwrap().clone();
-                factors.insert(*ff);
-                factors
-            }
-            None => {
-                // x is a prime number, the only factor is itself
-                let mut factors = HashSet::new();
-                factors.insert(x);
-                factors
-            }
-        };
-
-        // Update our caches
-        prime_factors.insert(x, factors);
-        if first_factor.is_none() {
-            previous_primes.push(x);
+    let mut consecutive = 0;
+    let mut x = 1;
+    while consecutive < n {
+        x += 1;
+        if utils::factor(x).len() == n {
+            consecutive += 1;
+        } else {
+            consecutive = 0;
         }
+    }
+    x - n + 1
+}
 
-        // Check if the n previous consecutive numbers have n distinct prime factors
-        let mut consecutive_numbers_have_n_factors = true;
-        for i in 0..n {
-            if prime_factors.get(&(x - i)).unwrap().len() != n {
-                consecutive_numbers_have_n_factors = false;
-                break;
-            }
-        }
-        if consecutive_numbers_have_n_factors {
-            break;
+/// Problem #47 : "Distinct Primes Factors".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 47,
+            title: "Distinct Primes Factors",
+            difficulty: 5,
+            tags: &[Tag::Primes],
+            techniques: &[Technique::Factor],
         }
+    }
 
-        // Next iteration
-        x += 1;
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("n", "Number of consecutive numbers and of distinct prime factors", 4),
+        ]
     }
-    x - n + 1
-}
 
-/// Solve the problem #47 and return the solution.
-pub fn solve() -> String {
-    distinct_prime_factors(4).to_string()
+    /// Solve the problem #47 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        // The product of the first 16 primes doesn't fit in a `usize`
+        Ok(distinct_prime_factors(params.integer_in("n", 1..=15)?).into())
+    }
 }
 
 #[cfg(test)]
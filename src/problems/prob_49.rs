@@ -1,3 +1,7 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::Params;
+use crate::problems::{Metadata, Problem, Tag, Technique};
 use crate::utils;
 
 /// Find the other sequence of 3 4-digits primes that are permutations of each
@@ -43,7 +47,22 @@ fn prime_permutation() -> String {
     panic!("Coudln't find the other sequence ?!")
 }
 
-/// Solve the problem #49 and return the solution.
-pub fn solve() -> String {
-    prime_permutation()
+/// Problem #49 : "Prime Permutations".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 49,
+            title: "Prime Permutations",
+            difficulty: 5,
+            tags: &[Tag::Primes, Tag::Combinatorics],
+            techniques: &[Technique::Primes, Technique::IsPrime, Technique::DigitsOf, Technique::PermutationsOf, Technique::DigitsToNumber],
+        }
+    }
+
+    /// Solve the problem #49 and return the solution.
+    fn compute(&self, _params: &Params) -> Result<Answer, Error> {
+        Ok(prime_permutation().into())
+    }
 }
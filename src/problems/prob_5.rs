@@ -1,8 +1,15 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag};
+
 /// Compute the smallest number that is divisible by each of the numbers from
 /// 1 to x.
-fn smallest_multiple_up_to(x: usize) -> usize {
+fn smallest_multiple_up_to(x: usize) -> Result<usize, Error> {
     if x < 2 {
-        panic!("Invalid input ({x}), please provide a number higher than 1.");
+        return Err(Error::InvalidArgument(format!(
+            "Invalid input ({x}), please provide a number higher than 1."
+        )));
     }
 
     let mut n = x;
@@ -25,12 +32,34 @@ fn smallest_multiple_up_to(x: usize) -> usize {
         // Go up by the highest number, to skip useless steps
         n += x;
     }
-    n
+    Ok(n)
 }
 
-/// Solve the problem #5 and return the solution.
-pub fn solve() -> String {
-    smallest_multiple_up_to(20).to_string()
+/// Problem #5 : "Smallest Multiple".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 5,
+            title: "Smallest Multiple",
+            difficulty: 5,
+            tags: &[Tag::Multiples],
+            techniques: &[],
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("limit", "The number should be divisible by every number from 1 to this limit", 20),
+        ]
+    }
+
+    /// Solve the problem #5 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        // The least common multiple of 1, ..., 47 doesn't fit in a `usize`
+        Ok(smallest_multiple_up_to(params.integer_in("limit", 0..=46)?)?.into())
+    }
 }
 
 #[cfg(test)]
@@ -39,18 +68,16 @@ mod tests {
 
     #[test]
     fn test_given_example() {
-        assert_eq!(smallest_multiple_up_to(10), 2520);
+        assert_eq!(smallest_multiple_up_to(10).unwrap(), 2520);
     }
 
     #[test]
-    #[should_panic]
     fn test_edge_case_0() {
-        smallest_multiple_up_to(0);
+        assert!(smallest_multiple_up_to(0).is_err());
     }
 
     #[test]
-    #[should_panic]
     fn test_edge_case_1() {
-        smallest_multiple_up_to(1);
+        assert!(smallest_multiple_up_to(1).is_err());
     }
 }
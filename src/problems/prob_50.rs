@@ -1,8 +1,13 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag, Technique};
 use crate::utils;
 
 /// Find the prime that can be written as a sum of prime, under the given limit
 fn largest_consecutive_prime_sum_under(limit: usize) -> usize {
-    let primes: Vec<usize> = utils::Primes::new_up_to(limit).collect();
+    let sieve = utils::Sieve::new(limit);
+    let primes: Vec<usize> = sieve.iter().collect();
 
     let mut best_sum = 0;
     let mut best_size = 0;
@@ -16,7 +21,7 @@ fn largest_consecutive_prime_sum_under(limit: usize) -> usize {
                 break;
             }
 
-            if j - i > best_size && utils::is_prime(sum) {
+            if j - i > best_size && sieve.is_prime(sum) {
                 best_sum = sum;
                 best_size = j - i;
             }
@@ -25,9 +30,30 @@ fn largest_consecutive_prime_sum_under(limit: usize) -> usize {
     best_sum
 }
 
-/// Solve the problem #50 and return the solution.
-pub fn solve() -> String {
-    largest_consecutive_prime_sum_under(1000000).to_string()
+/// Problem #50 : "Consecutive Prime Sum".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 50,
+            title: "Consecutive Prime Sum",
+            difficulty: 5,
+            tags: &[Tag::Primes],
+            techniques: &[Technique::Sieve],
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("limit", "The prime (and the sum) should be below this limit", 1000000),
+        ]
+    }
+
+    /// Solve the problem #50 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        Ok(largest_consecutive_prime_sum_under(params.integer_in("limit", 0..=100_000_000)?).into())
+    }
 }
 
 #[cfg(test)]
@@ -1,3 +1,7 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag, Technique};
 use crate::utils;
 use std::collections::HashSet;
 
@@ -48,12 +52,16 @@ fn replace_repeated_digits(digits: &Vec<usize>) -> Vec<Vec<usize>> {
 
 /// Find the smallest prime where x digits can be replaced with the same digit,
 /// yielding n primes out of the 10 generated numbers.
-fn smallest_n_prime_replacement(n: usize) -> usize {
+fn smallest_n_prime_replacement(n: usize) -> Result<usize, Error> {
     if n > 10 {
-        panic!("Can't generate {n} alternative numbers, there is only 10 digits.");
+        return Err(Error::InvalidArgument(format!(
+            "Can't generate {n} alternative numbers, there is only 10 digits."
+        )));
     }
     if n < 1 {
-        panic!("Can't get the smallest number of a 0-family, it's empty. Specify a different n.");
+        return Err(Error::InvalidArgument(String::from(
+            "Can't get the smallest number of a 0-family, it's empty. Specify a different n.",
+        )));
     }
 
     // let mut seen = HashSet::new();
@@ -71,7 +79,7 @@ fn smallest_n_prime_replacement(n: usize) -> usize {
                 let mut family = replace_ith_digit(&p_digits, i);
                 family.retain(|&m| utils::is_prime(m));
                 if family.len() == n {
-                    return *family.iter().min().unwrap();
+                    return Ok(*family.iter().min().unwrap());
                 }
             }
         }
@@ -81,16 +89,37 @@ fn smallest_n_prime_replacement(n: usize) -> usize {
         for family in families.iter_mut() {
             family.retain(|&m| utils::is_prime(m));
             if family.len() == n {
-                return *family.iter().min().unwrap();
+                return Ok(*family.iter().min().unwrap());
             }
         }
     }
     panic!("Unreachable code");
 }
 
-/// Solve the problem #51 and return the solution.
-pub fn solve() -> String {
-    smallest_n_prime_replacement(8).to_string()
+/// Problem #51 : "Prime Digit Replacements".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 51,
+            title: "Prime Digit Replacements",
+            difficulty: 15,
+            tags: &[Tag::Primes, Tag::Digits],
+            techniques: &[Technique::Primes, Technique::IsPrime, Technique::DigitsOf, Technique::DigitsToNumber],
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("family", "Number of primes in the family", 8),
+        ]
+    }
+
+    /// Solve the problem #51 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        Ok(smallest_n_prime_replacement(params.integer("family")?)?.into())
+    }
 }
 
 #[cfg(test)]
@@ -99,23 +128,21 @@ mod tests {
 
     #[test]
     fn test_given_example_1() {
-        assert_eq!(smallest_n_prime_replacement(6), 13);
+        assert_eq!(smallest_n_prime_replacement(6).unwrap(), 13);
     }
 
     #[test]
     fn test_given_example_2() {
-        assert_eq!(smallest_n_prime_replacement(7), 56003);
+        assert_eq!(smallest_n_prime_replacement(7).unwrap(), 56003);
     }
 
     #[test]
-    #[should_panic]
     fn test_wrong_input_too_big() {
-        smallest_n_prime_replacement(25);
+        assert!(smallest_n_prime_replacement(25).is_err());
     }
 
     #[test]
-    #[should_panic]
     fn test_wrong_input_too_small() {
-        smallest_n_prime_replacement(0);
+        assert!(smallest_n_prime_replacement(0).is_err());
     }
 }
@@ -1,3 +1,7 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag, Technique};
 use crate::utils;
 use std::collections::HashMap;
 
@@ -21,12 +25,16 @@ fn has_same_digits(ref_digits: &Vec<usize>, cmp_digits: &Vec<usize>) -> bool {
 
 /// Find the smallest positive integer x where x, 2x, ... nx contain the same
 /// digits.
-fn smallest_n_multiples_same_digits(n: usize) -> usize {
+fn smallest_n_multiples_same_digits(n: usize) -> Result<usize, Error> {
     if n > 9 {
-        panic!("No number exists where x and {n}x have the same digits. Specify a smaller n.");
+        return Err(Error::InvalidArgument(format!(
+            "No number exists where x and {n}x have the same digits. Specify a smaller n."
+        )));
     }
     if n < 1 {
-        panic!("n should be a positive integer (given : {n}).");
+        return Err(Error::InvalidArgument(format!(
+            "n should be a positive integer (given : {n})."
+        )));
     }
 
     let mutliples: Vec<usize> = (2..n + 1).rev().collect();
@@ -41,12 +49,33 @@ fn smallest_n_multiples_same_digits(n: usize) -> usize {
         }
         x += 1;
     }
-    x
+    Ok(x)
 }
 
-/// Solve the problem #52 and return the solution.
-pub fn solve() -> String {
-    smallest_n_multiples_same_digits(6).to_string()
+/// Problem #52 : "Permuted Multiples".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 52,
+            title: "Permuted Multiples",
+            difficulty: 5,
+            tags: &[Tag::Digits],
+            techniques: &[Technique::DigitsOf],
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("multiples", "x, 2x, ... up to this multiple should contain the same digits", 6),
+        ]
+    }
+
+    /// Solve the problem #52 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        Ok(smallest_n_multiples_same_digits(params.integer("multiples")?)?.into())
+    }
 }
 
 #[cfg(test)]
@@ -55,19 +84,17 @@ mod tests {
 
     #[test]
     fn test_given_example() {
-        assert_eq!(smallest_n_multiples_same_digits(2), 125874);
+        assert_eq!(smallest_n_multiples_same_digits(2).unwrap(), 125874);
     }
 
     #[test]
-    #[should_panic]
     fn test_wrong_input_too_big() {
-        smallest_n_multiples_same_digits(10);
+        assert!(smallest_n_multiples_same_digits(10).is_err());
     }
 
     #[test]
-    #[should_panic]
     fn test_wrong_input_too_small() {
-        smallest_n_multiples_same_digits(0);
+        assert!(smallest_n_multiples_same_digits(0).is_err());
     }
 
     #[test]
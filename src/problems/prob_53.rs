@@ -1,3 +1,7 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag};
 use std::collections::HashMap;
 
 struct CachedLimitedCombinations {
@@ -19,7 +23,7 @@ impl CachedLimitedCombinations {
             return 1;
         }
         if !self.cache.contains_key(&(n, r)) {
-            let mut c = self.of(n - 1, r - 1) + self.of(n - 1, r);
+            let mut c = self.of(n - 1, r - 1).saturating_add(self.of(n - 1, r));
             if c > self.limit {
                 c = self.limit + 1
             }
@@ -45,9 +49,34 @@ fn combinatorics_selections_above(max_n: usize, above_x: usize) -> usize {
     n_exceeding_value
 }
 
-/// Solve the problem #53 and return the solution.
-pub fn solve() -> String {
-    combinatorics_selections_above(100, 1000000).to_string()
+/// Problem #53 : "Combinatoric Selections".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 53,
+            title: "Combinatoric Selections",
+            difficulty: 5,
+            tags: &[Tag::Combinatorics, Tag::DynamicProgramming],
+            techniques: &[],
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("max_n", "Maximum value of n", 100),
+            Param::new("threshold", "Selections are counted when they exceed this value", 1000000),
+        ]
+    }
+
+    /// Solve the problem #53 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        let max_n = params.integer_in("max_n", 0..=1000)?;
+        // Values above the threshold are stored as `threshold + 1`
+        let threshold = params.integer_in("threshold", 0..=usize::MAX - 1)?;
+        Ok(combinatorics_selections_above(max_n, threshold).into())
+    }
 }
 
 #[cfg(test)]
@@ -1,3 +1,8 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag};
+
 /// Possible ranks of a card.
 #[derive(Clone, Copy, PartialEq)]
 enum Rank {
@@ -26,33 +31,38 @@ struct Card {
 
 impl Card {
     /// Parse the given card descriptor and create the associated card.
-    pub fn new(c: &str) -> Self {
-        assert_eq!(c.len(), 2);
-        let rank = match c.chars().nth(0).unwrap() {
-            '2' | '3' | '4' | '5' | '6' | '7' | '8' | '9' => Rank::Pip(
-                c.chars()
-                    .nth(0)
-                    .unwrap()
-                    .to_digit(10)
-                    .unwrap()
-                    .try_into()
-                    .unwrap(),
-            ),
+    pub fn new(c: &str) -> Result<Self, Error> {
+        let mut chars = c.chars();
+        let (Some(rank), Some(suit), None) = (chars.next(), chars.next(), chars.next()) else {
+            return Err(Error::MalformedInput(format!(
+                "`{c}` is not a card descriptor, it should be 2 characters long."
+            )));
+        };
+        let rank = match rank {
+            '2'..='9' => Rank::Pip(rank.to_digit(10).unwrap() as usize),
             'T' => Rank::Pip(10),
             'J' => Rank::Jack,
             'Q' => Rank::Queen,
             'K' => Rank::King,
             'A' => Rank::Ace,
-            _ => panic!("Unknown card rank : {}", c.chars().nth(0).unwrap()),
+            _ => {
+                return Err(Error::MalformedInput(format!(
+                    "Unknown card rank : {rank}"
+                )))
+            }
         };
-        let suit = match c.chars().nth(1).unwrap() {
+        let suit = match suit {
             'C' => Suit::Club,
             'D' => Suit::Diamond,
             'H' => Suit::Heart,
             'S' => Suit::Spade,
-            _ => panic!("Unknown card suit : {}", c.chars().nth(1).unwrap()),
+            _ => {
+                return Err(Error::MalformedInput(format!(
+                    "Unknown card suit : {suit}"
+                )))
+            }
         };
-        Self { rank, suit }
+        Ok(Self { rank, suit })
     }
 
     /// Compute the value of this card.
@@ -110,12 +120,14 @@ struct Hand {
 
 impl Hand {
     /// Constructor for the Hand.
-    pub fn new(mut cards: Vec<Card>) -> Self {
-        if cards.len() == 0 {
-            panic!("A hand cannot be empty !");
+    pub fn new(mut cards: Vec<Card>) -> Result<Self, Error> {
+        if cards.is_empty() {
+            return Err(Error::MalformedInput(String::from(
+                "A hand cannot be empty !",
+            )));
         }
         cards.sort_by_key(|c| c.value());
-        Self { cards }
+        Ok(Self { cards })
     }
 
     /// Compute the values of the hand.
@@ -262,18 +274,18 @@ impl Hand {
 
 /// Find the number of time where player 1 wins, given a list of 2 hands with
 /// each 5 cards.
-fn n_win_player_1(hands: &str) -> usize {
+fn n_win_player_1(hands: &str) -> Result<usize, Error> {
     let mut p1_n_wins = 0;
     for hand in hands.lines() {
         let mut cards = Vec::new();
         for card_descriptor in hand.split_whitespace() {
-            cards.push(Card::new(card_descriptor));
+            cards.push(Card::new(card_descriptor)?);
         }
         let p2_cards = cards.split_off(cards.len() / 2);
         let p1_cards = cards;
 
-        let p1_hand_values = Hand::new(p1_cards).values();
-        let p2_hand_values = Hand::new(p2_cards).values();
+        let p1_hand_values = Hand::new(p1_cards)?.values();
+        let p2_hand_values = Hand::new(p2_cards)?.values();
 
         for i in 0..p1_hand_values.len() {
             if p1_hand_values[i] > p2_hand_values[i] {
@@ -286,13 +298,33 @@ fn n_win_player_1(hands: &str) -> usize {
             }
         }
     }
-    p1_n_wins
+    Ok(p1_n_wins)
 }
 
-/// Solve the problem #54 and return the solution.
-pub fn solve() -> String {
-    let poker_hands = include_str!("data/poker.txt");
-    n_win_player_1(poker_hands).to_string()
+/// Problem #54 : "Poker Hands".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 54,
+            title: "Poker Hands",
+            difficulty: 10,
+            tags: &[Tag::Games],
+            techniques: &[],
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("hands", "Hands of both players, one game per line", include_str!("data/poker.txt")),
+        ]
+    }
+
+    /// Solve the problem #54 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        Ok(n_win_player_1(params.text("hands")?)?.into())
+    }
 }
 
 #[cfg(test)]
@@ -301,86 +333,91 @@ mod tests {
 
     #[test]
     fn test_given_example_1() {
-        assert_eq!(n_win_player_1("5H 5C 6S 7S KD 2C 3S 8S 8D TD"), 0);
+        assert_eq!(n_win_player_1("5H 5C 6S 7S KD 2C 3S 8S 8D TD").unwrap(), 0);
     }
 
     #[test]
     fn test_given_example_2() {
-        assert_eq!(n_win_player_1("5D 8C 9S JS AC 2C 5C 7D 8S QH"), 1);
+        assert_eq!(n_win_player_1("5D 8C 9S JS AC 2C 5C 7D 8S QH").unwrap(), 1);
     }
 
     #[test]
     fn test_given_example_3() {
-        assert_eq!(n_win_player_1("2D 9C AS AH AC 3D 6D 7D TD QD"), 0);
+        assert_eq!(n_win_player_1("2D 9C AS AH AC 3D 6D 7D TD QD").unwrap(), 0);
     }
 
     #[test]
     fn test_given_example_4() {
-        assert_eq!(n_win_player_1("4D 6S 9H QH QC 3D 6D 7H QD QS"), 1);
+        assert_eq!(n_win_player_1("4D 6S 9H QH QC 3D 6D 7H QD QS").unwrap(), 1);
     }
 
     #[test]
     fn test_given_example_5() {
-        assert_eq!(n_win_player_1("2H 2D 4C 4D 4S 3C 3D 3S 9S 9D"), 1);
+        assert_eq!(n_win_player_1("2H 2D 4C 4D 4S 3C 3D 3S 9S 9D").unwrap(), 1);
     }
 
     #[test]
-    #[should_panic]
     fn test_build_card_wrong_rank_1() {
-        Card::new("1H");
+        assert!(Card::new("1H").is_err());
     }
 
     #[test]
-    #[should_panic]
     fn test_build_card_wrong_rank_x() {
-        Card::new("XH");
+        assert!(Card::new("XH").is_err());
     }
 
     #[test]
-    #[should_panic]
     fn test_build_card_wrong_suit() {
-        Card::new("4X");
+        assert!(Card::new("4X").is_err());
+    }
+
+    #[test]
+    fn test_build_card_wrong_length() {
+        assert!(Card::new("4").is_err());
+        assert!(Card::new("10H").is_err());
     }
 
     #[test]
     fn test_card_values() {
-        assert!(Card::new("2H").value() < Card::new("3H").value());
-        assert!(Card::new("3H").value() < Card::new("4H").value());
-        assert!(Card::new("4H").value() < Card::new("5H").value());
-        assert!(Card::new("5H").value() < Card::new("6H").value());
-        assert!(Card::new("6H").value() < Card::new("7H").value());
-        assert!(Card::new("7H").value() < Card::new("8H").value());
-        assert!(Card::new("8H").value() < Card::new("9H").value());
-        assert!(Card::new("9H").value() < Card::new("TH").value());
-        assert!(Card::new("TH").value() < Card::new("JH").value());
-        assert!(Card::new("JH").value() < Card::new("QH").value());
-        assert!(Card::new("QH").value() < Card::new("KH").value());
-        assert!(Card::new("KH").value() < Card::new("AH").value());
+        assert!(Card::new("2H").unwrap().value() < Card::new("3H").unwrap().value());
+        assert!(Card::new("3H").unwrap().value() < Card::new("4H").unwrap().value());
+        assert!(Card::new("4H").unwrap().value() < Card::new("5H").unwrap().value());
+        assert!(Card::new("5H").unwrap().value() < Card::new("6H").unwrap().value());
+        assert!(Card::new("6H").unwrap().value() < Card::new("7H").unwrap().value());
+        assert!(Card::new("7H").unwrap().value() < Card::new("8H").unwrap().value());
+        assert!(Card::new("8H").unwrap().value() < Card::new("9H").unwrap().value());
+        assert!(Card::new("9H").unwrap().value() < Card::new("TH").unwrap().value());
+        assert!(Card::new("TH").unwrap().value() < Card::new("JH").unwrap().value());
+        assert!(Card::new("JH").unwrap().value() < Card::new("QH").unwrap().value());
+        assert!(Card::new("QH").unwrap().value() < Card::new("KH").unwrap().value());
+        assert!(Card::new("KH").unwrap().value() < Card::new("AH").unwrap().value());
     }
 
     #[test]
-    #[should_panic]
     fn test_empty_hand() {
-        Hand::new(Vec::new());
+        assert!(Hand::new(Vec::new()).is_err());
     }
 
     #[test]
     fn test_hand_is_same_suit() {
-        let h = Hand::new(vec![Card::new("2H"), Card::new("4H"), Card::new("JH")]);
+        let h = Hand::new(vec![Card::new("2H").unwrap(), Card::new("4H").unwrap(), Card::new("JH").unwrap()])
+        .unwrap();
         assert!(h.is_same_suit());
 
-        let h = Hand::new(vec![Card::new("2H"), Card::new("4S"), Card::new("JH")]);
+        let h = Hand::new(vec![Card::new("2H").unwrap(), Card::new("4S").unwrap(), Card::new("JH").unwrap()])
+        .unwrap();
         assert!(!h.is_same_suit());
     }
 
     #[test]
     fn test_hand_ordered() {
         let h = Hand::new(vec![
-            Card::new("JH"),
-            Card::new("2S"),
-            Card::new("AD"),
-            Card::new("4C"),
-        ]);
+            Card::new("JH").unwrap(),
+            Card::new("2S").unwrap(),
+            Card::new("AD").unwrap(),
+            Card::new("4C").unwrap(),
+        ])
+        .unwrap();
         assert_eq!(h.cards[0].suit, Suit::Spade);
         assert_eq!(h.cards[1].suit, Suit::Club);
         assert_eq!(h.cards[2].suit, Suit::Heart);
@@ -389,30 +426,34 @@ mod tests {
 
     #[test]
     fn test_hand_is_consecutive() {
-        let h = Hand::new(vec![Card::new("2H"), Card::new("4S"), Card::new("JH")]);
+        let h = Hand::new(vec![Card::new("2H").unwrap(), Card::new("4S").unwrap(), Card::new("JH").unwrap()])
+        .unwrap();
         assert!(!h.is_consecutive());
 
-        let h = Hand::new(vec![Card::new("2H"), Card::new("3S"), Card::new("4H")]);
+        let h = Hand::new(vec![Card::new("2H").unwrap(), Card::new("3S").unwrap(), Card::new("4H").unwrap()])
+        .unwrap();
         assert!(h.is_consecutive());
 
         let h = Hand::new(vec![
-            Card::new("9H"),
-            Card::new("TS"),
-            Card::new("JH"),
-            Card::new("QS"),
-        ]);
+            Card::new("9H").unwrap(),
+            Card::new("TS").unwrap(),
+            Card::new("JH").unwrap(),
+            Card::new("QS").unwrap(),
+        ])
+        .unwrap();
         assert!(h.is_consecutive());
     }
 
     #[test]
     fn test_score_royal_flush() {
         let values = Hand::new(vec![
-            Card::new("TH"),
-            Card::new("JH"),
-            Card::new("QH"),
-            Card::new("KH"),
-            Card::new("AH"),
+            Card::new("TH").unwrap(),
+            Card::new("JH").unwrap(),
+            Card::new("QH").unwrap(),
+            Card::new("KH").unwrap(),
+            Card::new("AH").unwrap(),
         ])
+        .unwrap()
         .values();
         assert_eq!(values.len(), 1);
         assert_eq!(values[0], score::ROYAL_FLUSH);
@@ -421,12 +462,13 @@ mod tests {
     #[test]
     fn test_score_straight_flush() {
         let values = Hand::new(vec![
-            Card::new("7H"),
-            Card::new("8H"),
-            Card::new("9H"),
-            Card::new("TH"),
-            Card::new("JH"),
+            Card::new("7H").unwrap(),
+            Card::new("8H").unwrap(),
+            Card::new("9H").unwrap(),
+            Card::new("TH").unwrap(),
+            Card::new("JH").unwrap(),
         ])
+        .unwrap()
         .values();
         assert_eq!(values.len(), 1);
         assert!(values[0] > score::STRAIGHT_FLUSH);
@@ -435,12 +477,13 @@ mod tests {
     #[test]
     fn test_score_four_of_a_kind() {
         let values = Hand::new(vec![
-            Card::new("4H"),
-            Card::new("6C"),
-            Card::new("6S"),
-            Card::new("6D"),
-            Card::new("6H"),
+            Card::new("4H").unwrap(),
+            Card::new("6C").unwrap(),
+            Card::new("6S").unwrap(),
+            Card::new("6D").unwrap(),
+            Card::new("6H").unwrap(),
         ])
+        .unwrap()
         .values();
         assert_eq!(values.len(), 2);
         assert!(values[0] > score::FOUR_OF_A_KIND);
@@ -450,12 +493,13 @@ mod tests {
     #[test]
     fn test_score_full_house() {
         let values = Hand::new(vec![
-            Card::new("2H"),
-            Card::new("2S"),
-            Card::new("2D"),
-            Card::new("6D"),
-            Card::new("6H"),
+            Card::new("2H").unwrap(),
+            Card::new("2S").unwrap(),
+            Card::new("2D").unwrap(),
+            Card::new("6D").unwrap(),
+            Card::new("6H").unwrap(),
         ])
+        .unwrap()
         .values();
         assert_eq!(values.len(), 1);
         assert!(values[0] > score::FULL_HOUSE);
@@ -464,12 +508,13 @@ mod tests {
     #[test]
     fn test_score_flush() {
         let values = Hand::new(vec![
-            Card::new("2H"),
-            Card::new("4H"),
-            Card::new("6H"),
-            Card::new("8H"),
-            Card::new("QH"),
+            Card::new("2H").unwrap(),
+            Card::new("4H").unwrap(),
+            Card::new("6H").unwrap(),
+            Card::new("8H").unwrap(),
+            Card::new("QH").unwrap(),
         ])
+        .unwrap()
         .values();
         assert_eq!(values.len(), 1);
         assert!(values[0] > score::FLUSH);
@@ -478,12 +523,13 @@ mod tests {
     #[test]
     fn test_score_straight() {
         let values = Hand::new(vec![
-            Card::new("2H"),
-            Card::new("3S"),
-            Card::new("4D"),
-            Card::new("5H"),
-            Card::new("6H"),
+            Card::new("2H").unwrap(),
+            Card::new("3S").unwrap(),
+            Card::new("4D").unwrap(),
+            Card::new("5H").unwrap(),
+            Card::new("6H").unwrap(),
         ])
+        .unwrap()
         .values();
         assert_eq!(values.len(), 1);
         assert!(values[0] > score::STRAIGHT);
@@ -492,12 +538,13 @@ mod tests {
     #[test]
     fn test_score_three_of_a_kind() {
         let values = Hand::new(vec![
-            Card::new("2H"),
-            Card::new("3S"),
-            Card::new("6S"),
-            Card::new("6D"),
-            Card::new("6H"),
+            Card::new("2H").unwrap(),
+            Card::new("3S").unwrap(),
+            Card::new("6S").unwrap(),
+            Card::new("6D").unwrap(),
+            Card::new("6H").unwrap(),
         ])
+        .unwrap()
         .values();
         assert_eq!(values.len(), 3);
         assert!(values[0] > score::THREE_OF_A_KIND);
@@ -508,12 +555,13 @@ mod tests {
     #[test]
     fn test_score_two_pairs() {
         let values = Hand::new(vec![
-            Card::new("8H"),
-            Card::new("3S"),
-            Card::new("8S"),
-            Card::new("6D"),
-            Card::new("6H"),
+            Card::new("8H").unwrap(),
+            Card::new("3S").unwrap(),
+            Card::new("8S").unwrap(),
+            Card::new("6D").unwrap(),
+            Card::new("6H").unwrap(),
         ])
+        .unwrap()
         .values();
         assert_eq!(values.len(), 2);
         assert!(values[0] > score::TWO_PAIRS);
@@ -523,12 +571,13 @@ mod tests {
     #[test]
     fn test_score_pair() {
         let values = Hand::new(vec![
-            Card::new("8H"),
-            Card::new("3S"),
-            Card::new("8S"),
-            Card::new("9D"),
-            Card::new("KH"),
+            Card::new("8H").unwrap(),
+            Card::new("3S").unwrap(),
+            Card::new("8S").unwrap(),
+            Card::new("9D").unwrap(),
+            Card::new("KH").unwrap(),
         ])
+        .unwrap()
         .values();
         assert_eq!(values.len(), 4);
         assert!(values[0] > score::PAIR);
@@ -540,12 +589,13 @@ mod tests {
     #[test]
     fn test_score_high_card() {
         let values = Hand::new(vec![
-            Card::new("8H"),
-            Card::new("3S"),
-            Card::new("5S"),
-            Card::new("9D"),
-            Card::new("KH"),
+            Card::new("8H").unwrap(),
+            Card::new("3S").unwrap(),
+            Card::new("5S").unwrap(),
+            Card::new("9D").unwrap(),
+            Card::new("KH").unwrap(),
         ])
+        .unwrap()
         .values();
         assert_eq!(values.len(), 5);
         assert_eq!(values[0], 13);
@@ -1,3 +1,7 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag, Technique};
 use crate::utils;
 
 const ASSUME_LYCHREL_AFTER: usize = 50;
@@ -34,9 +38,30 @@ fn lychrel_numbers_under(limit: usize) -> usize {
     n_lychrel
 }
 
-/// Solve the problem #55 and return the solution.
-pub fn solve() -> String {
-    lychrel_numbers_under(10000).to_string()
+/// Problem #55 : "Lychrel Numbers".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 55,
+            title: "Lychrel Numbers",
+            difficulty: 5,
+            tags: &[Tag::Palindromes, Tag::BigNumbers],
+            techniques: &[Technique::BigInt],
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("limit", "Lychrel numbers are counted up to this limit", 10000),
+        ]
+    }
+
+    /// Solve the problem #55 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        Ok(lychrel_numbers_under(params.integer_in("limit", 0..=1_000_000_000)?).into())
+    }
 }
 
 #[cfg(test)]
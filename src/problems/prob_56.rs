@@ -1,3 +1,7 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag, Technique};
 use crate::utils;
 
 /// Find the maximum sum of a^b, where a and b can be any number under the
@@ -8,7 +12,7 @@ fn max_power_sum(limit: usize) -> usize {
         let mut x = utils::BigInt::from(a);
         for _b in 1..limit {
             x *= a;
-            let digit_sum = x.digits.iter().sum();
+            let digit_sum = x.digits().iter().sum();
             if digit_sum > max_sum {
                 max_sum = digit_sum
             }
@@ -17,7 +21,28 @@ fn max_power_sum(limit: usize) -> usize {
     max_sum
 }
 
-/// Solve the problem #56 and return the solution.
-pub fn solve() -> String {
-    max_power_sum(100).to_string()
+/// Problem #56 : "Powerful Digit Sum".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 56,
+            title: "Powerful Digit Sum",
+            difficulty: 5,
+            tags: &[Tag::BigNumbers, Tag::Digits],
+            techniques: &[Technique::BigInt],
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("limit", "a and b are taken below this limit", 100),
+        ]
+    }
+
+    /// Solve the problem #56 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        Ok(max_power_sum(params.integer_in("limit", 0..=1000)?).into())
+    }
 }
@@ -1,3 +1,7 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag, Technique};
 use crate::utils;
 
 /// When expanding the square root up to the given number of expansions, find
@@ -25,22 +29,40 @@ fn n_fat_square_root_expansions(n_expansions: usize) -> usize {
     let mut expansion_i = 0;
 
     while expansion_i < n_expansions {
-        let new_b = &a + &b;
-        b *= 2;
-        a = &b + &a;
-        b = new_b;
+        (a, b) = (&a + &b * 2, &a + &b);
         expansion_i += 1;
 
-        if a.digits.len() > b.digits.len() {
+        if a.num_digits() > b.num_digits() {
             n_fat_expansions += 1;
         }
     }
     n_fat_expansions
 }
 
-/// Solve the problem #57 and return the solution.
-pub fn solve() -> String {
-    n_fat_square_root_expansions(1000).to_string()
+/// Problem #57 : "Square Root Convergents".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 57,
+            title: "Square Root Convergents",
+            difficulty: 5,
+            tags: &[Tag::Fractions, Tag::BigNumbers],
+            techniques: &[Technique::BigInt],
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("expansions", "Number of expansions", 1000),
+        ]
+    }
+
+    /// Solve the problem #57 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        Ok(n_fat_square_root_expansions(params.integer_in("expansions", 0..=100_000)?).into())
+    }
 }
 
 #[cfg(test)]
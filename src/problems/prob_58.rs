@@ -1,3 +1,7 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag, Technique};
 use crate::utils;
 
 /// Find the size of the spiral's side which give a ratio of prime on the
@@ -8,12 +12,20 @@ fn spiral_len_prime_ratio_under(max_ratio: f64) -> usize {
     let mut ratio = 1.0;
     let mut n_prime = 0.0;
     let mut n = 1.0;
+    let mut sieve = utils::Sieve::new(1000);
 
     while ratio > max_ratio {
         spiral_size += 2;
+
+        // The sieve checks numbers up to the square of its limit
+        let max_x = x + 4 * spiral_size;
+        if max_x / sieve.limit() >= sieve.limit() {
+            sieve = utils::Sieve::new(sieve.limit() * 2);
+        }
+
         for _ in 0..4 {
             x += spiral_size;
-            if utils::is_prime(x) {
+            if sieve.is_prime(x) {
                 n_prime += 1.0;
             }
             n += 1.0;
@@ -23,9 +35,36 @@ fn spiral_len_prime_ratio_under(max_ratio: f64) -> usize {
     spiral_size + 1
 }
 
-/// Solve the problem #58 and return the solution.
-pub fn solve() -> String {
-    spiral_len_prime_ratio_under(0.1).to_string()
+/// Problem #58 : "Spiral Primes".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 58,
+            title: "Spiral Primes",
+            difficulty: 5,
+            tags: &[Tag::Primes, Tag::Grids],
+            techniques: &[Technique::Sieve],
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("ratio", "Ratio of primes along the diagonals to go under", 0.1),
+        ]
+    }
+
+    /// Solve the problem #58 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        let ratio = params.float("ratio")?;
+        if ratio <= 0.0 {
+            return Err(Error::InvalidArgument(format!(
+                "The ratio should be positive (given : {ratio})."
+            )));
+        }
+        Ok(spiral_len_prime_ratio_under(ratio).into())
+    }
 }
 
 #[cfg(test)]
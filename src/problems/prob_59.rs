@@ -1,3 +1,8 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag};
+
 /// Decrypt a given list of values with a given password with XOR decryption.
 fn decrypt(crypted_message: &Vec<u8>, password: &Vec<u8>) -> String {
     let mut message = String::new();
@@ -17,11 +22,19 @@ fn decrypt(crypted_message: &Vec<u8>, password: &Vec<u8>) -> String {
 /// properly decrypted, space will not be properly decrypted and we will not
 /// have several words. If the text is properly decrypted, the space characters
 /// will be properly decrypted and we will have several words.
-fn decipher(ciphered_text: &str, password_len: usize) -> usize {
+fn decipher(ciphered_text: &str, password_len: usize) -> Result<usize, Error> {
+    if password_len == 0 {
+        return Err(Error::InvalidArgument(String::from(
+            "The password should contain at least one letter.",
+        )));
+    }
+
     // Read the ASCII values from the ciphered text
     let mut ciphered_values = Vec::new();
     for v in ciphered_text.split(',') {
-        let v: u8 = v.parse().unwrap();
+        let v: u8 = v.parse().map_err(|_| {
+            Error::MalformedInput(format!("`{v}` is not a valid ASCII value."))
+        })?;
         ciphered_values.push(v);
     }
 
@@ -52,13 +65,37 @@ fn decipher(ciphered_text: &str, password_len: usize) -> usize {
     }
 
     // Return the sum of uncrypted ASCII values
-    best_message.chars().map(|c| c as usize).sum()
+    Ok(best_message.chars().map(|c| c as usize).sum())
 }
 
-/// Solve the problem #59 and return the solution.
-pub fn solve() -> String {
-    let ciphered_text = include_str!("data/cipher.txt");
-    decipher(ciphered_text, 3).to_string()
+/// Problem #59 : "XOR Decryption".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 59,
+            title: "XOR Decryption",
+            difficulty: 5,
+            tags: &[Tag::Cryptography, Tag::Words],
+            techniques: &[],
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("cipher", "Comma-separated list of encrypted ASCII codes", include_str!("data/cipher.txt")),
+            Param::new("password_len", "Number of lowercase letters in the password", 3),
+        ]
+    }
+
+    /// Solve the problem #59 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        let cipher = params.text("cipher")?;
+        // Letters beyond the length of the message are never used
+        let password_len = params.integer_in("password_len", 0..=cipher.split(',').count())?;
+        Ok(decipher(cipher, password_len)?.into())
+    }
 }
 
 #[cfg(test)]
@@ -75,6 +112,17 @@ mod tests {
         assert_eq!(decrypt(&encrypted_code, &password), original);
     }
 
+    #[test]
+    fn test_decipher_malformed_input() {
+        assert!(decipher("65,66,x", 1).is_err());
+        assert!(decipher("65,66,300", 1).is_err());
+    }
+
+    #[test]
+    fn test_decipher_empty_password() {
+        assert!(decipher("65,66", 0).is_err());
+    }
+
     #[test]
     fn test_encrypt_decrypt_multiple_chars() {
         let original = String::from("This is a test");
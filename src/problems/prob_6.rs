@@ -1,20 +1,52 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag};
+
 /// Compute the difference between the sum of the squares of the first n
 /// numbers and the square of the sum of the first n numbers.
-fn sum_square_difference(x: usize) -> usize {
-    let mut sum_squares = 0;
-    let mut sum = 0;
+fn sum_square_difference(x: usize) -> Result<usize, Error> {
+    let overflow = || Error::Overflow(format!("The square of the sum of the first {x} numbers does not fit in a usize."));
+    let mut sum_squares: usize = 0;
+    let mut sum: usize = 0;
 
     for i in 0..x + 1 {
-        sum += i;
-        sum_squares += i * i;
+        sum = sum.checked_add(i).ok_or_else(overflow)?;
+        sum_squares = i
+            .checked_mul(i)
+            .and_then(|sq| sum_squares.checked_add(sq))
+            .ok_or_else(overflow)?;
     }
 
-    sum * sum - sum_squares
+    let square_sum = sum.checked_mul(sum).ok_or_else(overflow)?;
+    Ok(square_sum - sum_squares)
 }
 
-/// Solve the problem #6 and return the solution.
-pub fn solve() -> String {
-    sum_square_difference(100).to_string()
+/// Problem #6 : "Sum Square Difference".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 6,
+            title: "Sum Square Difference",
+            difficulty: 5,
+            tags: &[Tag::Arithmetic],
+            techniques: &[],
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("limit", "Number of natural numbers considered", 100),
+        ]
+    }
+
+    /// Solve the problem #6 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        // Above, the square of the sum doesn't fit in a `usize`
+        Ok(sum_square_difference(params.integer_in("limit", 0..=92_681)?)?.into())
+    }
 }
 
 #[cfg(test)]
@@ -23,6 +55,11 @@ mod tests {
 
     #[test]
     fn test_given_example() {
-        assert_eq!(sum_square_difference(10), 2640);
+        assert_eq!(sum_square_difference(10).unwrap(), 2640);
+    }
+
+    #[test]
+    fn test_overflow() {
+        assert!(sum_square_difference(1000000).is_err());
     }
 }
@@ -1,8 +1,31 @@
-use crate::problems::prob_18;
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{prob_18, Metadata, Problem, Tag};
 
-/// Solve the problem #67 and return the solution.
-pub fn solve() -> String {
-    let triangle_content = include_str!("data/triangle.txt");
-    let triangle = prob_18::Triangle::new(triangle_content);
-    triangle.max_path().to_string()
+/// Problem #67 : "Maximum Path Sum II".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 67,
+            title: "Maximum Path Sum II",
+            difficulty: 5,
+            tags: &[Tag::DynamicProgramming],
+            techniques: &[],
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("triangle", "Triangle of numbers, one row per line", include_str!("data/triangle.txt")),
+        ]
+    }
+
+    /// Solve the problem #67 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        let triangle = prob_18::Triangle::new(params.text("triangle")?)?;
+        Ok(triangle.max_path().into())
+    }
 }
@@ -1,19 +1,49 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag, Technique};
 use crate::utils;
 
+/// Upper bound of the n-th prime number (Rosser's theorem : for n >= 6, the
+/// n-th prime is below n * (ln n + ln ln n)).
+fn nth_prime_upper_bound(n: usize) -> usize {
+    if n < 6 {
+        return 13;
+    }
+    let n = n as f64;
+    (n * (n.ln() + n.ln().ln())).ceil() as usize
+}
+
 /// Compute the n-th prime number.
 fn nth_prime(n: usize) -> usize {
-    let mut primes = utils::Primes::new();
+    let sieve = utils::Sieve::new(nth_prime_upper_bound(n));
+    sieve.iter().nth(n - 1).unwrap()
+}
+
+/// Problem #7 : "10 001st Prime".
+pub struct Solution;
 
-    let mut p = 1;
-    for _i in 0..n {
-        p = primes.next().unwrap();
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 7,
+            title: "10 001st Prime",
+            difficulty: 5,
+            tags: &[Tag::Primes],
+            techniques: &[Technique::Sieve],
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("n", "Rank of the prime number to find", 10001),
+        ]
     }
-    p
-}
 
-/// Solve the problem #7 and return the solution.
-pub fn solve() -> String {
-    nth_prime(10001).to_string()
+    /// Solve the problem #7 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        Ok(nth_prime(params.integer_in("n", 1..=100_000_000)?).into())
+    }
 }
 
 #[cfg(test)]
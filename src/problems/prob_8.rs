@@ -1,3 +1,8 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag};
+
 /// Compute the greatest product of the n adjacent digits in the
 /// problem-specific 1000-digit number.
 fn greatest_product_adjacent(n: usize) -> u64 {
@@ -59,9 +64,30 @@ fn greatest_product_adjacent(n: usize) -> u64 {
     max_sum
 }
 
-/// Solve the problem #8 and return the solution.
-pub fn solve() -> String {
-    greatest_product_adjacent(13).to_string()
+/// Problem #8 : "Largest Product in a Series".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 8,
+            title: "Largest Product in a Series",
+            difficulty: 5,
+            tags: &[Tag::Digits],
+            techniques: &[],
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("digits", "Number of adjacent digits in the product", 13),
+        ]
+    }
+
+    /// Solve the problem #8 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        Ok(greatest_product_adjacent(params.integer_in("digits", 1..=20)?).into())
+    }
 }
 
 #[cfg(test)]
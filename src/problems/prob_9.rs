@@ -1,8 +1,13 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::params::{Param, Params};
+use crate::problems::{Metadata, Problem, Tag};
+
 /// Compute a pythagorean triplet that sums to the given number, and return the
 /// product of the triplet.
-fn pythagorean_triplet(result: usize) -> usize {
+fn pythagorean_triplet(result: usize) -> Result<usize, Error> {
     if result < 6 {
-        panic!("Pythagorean triplets are natural number, so the minimum result is 6 ({result} given) because 1 + 2 + 3 = 6");
+        return Err(Error::InvalidArgument(format!("Pythagorean triplets are natural number, so the minimum result is 6 ({result} given) because 1 + 2 + 3 = 6")));
     }
 
     // We start from 1 because we know a, b, c are natural numbers
@@ -14,18 +19,42 @@ fn pythagorean_triplet(result: usize) -> usize {
                 // than checking if they are a pythagorean triplet
                 if a + b + c == result {
                     if a * a + b * b == c * c {
-                        return a * b * c;
+                        return Ok(a * b * c);
                     }
                 }
             }
         }
     }
-    panic!("There is no pythagorean triplet that satisfies this sum ({result})");
+    Err(Error::InvalidArgument(format!(
+        "There is no pythagorean triplet that satisfies this sum ({result})"
+    )))
 }
 
-/// Solve the problem #9 and return the solution.
-pub fn solve() -> String {
-    pythagorean_triplet(1000).to_string()
+/// Problem #9 : "Special Pythagorean Triplet".
+pub struct Solution;
+
+impl Problem for Solution {
+    fn metadata(&self) -> &'static Metadata {
+        &Metadata {
+            id: 9,
+            title: "Special Pythagorean Triplet",
+            difficulty: 5,
+            tags: &[Tag::Geometry],
+            techniques: &[],
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("sum", "Sum of the Pythagorean triplet", 1000),
+        ]
+    }
+
+    /// Solve the problem #9 and return the solution.
+    fn compute(&self, params: &Params) -> Result<Answer, Error> {
+        // Above, the product of the triplet may not fit in a `usize`
+        Ok(pythagorean_triplet(params.integer_in("sum", 0..=7_000_000)?)?.into())
+    }
 }
 
 #[cfg(test)]
@@ -34,18 +63,16 @@ mod tests {
 
     #[test]
     fn test_given_example() {
-        assert_eq!(pythagorean_triplet(12), 60);
+        assert_eq!(pythagorean_triplet(12).unwrap(), 60);
     }
 
     #[test]
-    #[should_panic]
     fn test_too_small_result() {
-        pythagorean_triplet(2);
+        assert!(pythagorean_triplet(2).is_err());
     }
 
     #[test]
-    #[should_panic]
     fn test_non_existing_triplet() {
-        pythagorean_triplet(7);
+        assert!(pythagorean_triplet(7).is_err());
     }
 }
@@ -0,0 +1,1328 @@
+use super::Sieve;
+use crate::error::Error;
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops;
+use std::str::FromStr;
+
+/// Base of the limbs: each limb holds 9 decimal digits.
+const BASE: u64 = 1_000_000_000;
+
+/// Number of decimal digits in a limb.
+const LIMB_DIGITS: usize = 9;
+
+/// Default number of limbs from which the multiplication uses Karatsuba's
+/// method rather than the schoolbook one.
+const KARATSUBA_THRESHOLD: usize = 32;
+
+/// Structure used to deal with arbtrarily large numbers.
+///
+/// Addition, subtraction, multiplication, division and remainder are
+/// supported between two BigInt and between a BigInt and a `usize`, on values
+/// and references, along with their `*Assign` forms.
+///
+/// # Notes
+/// BigInt are unsigned: like for the primitive unsigned integers, a
+/// subtraction with a negative result panics, as well as a division by zero.
+/// Use `checked_sub`, `checked_div` and `checked_rem` to get `None` instead.
+///
+/// The number is stored in base 10^9, so that printing it or getting its
+/// decimal digits stays cheap, while each operation works on 9 digits at once.
+///
+/// # Examples
+/// ```
+/// use reuler::utils::BigInt;
+///
+/// let mut x = BigInt::from(128);
+/// let y = BigInt::new();  // It's 0
+/// x = &x + &y;
+/// x *= 2;
+///
+/// assert_eq!(x.to_string(), String::from("256"));
+/// assert_eq!(x.digits(), vec![6, 5, 2]);
+///
+/// let big: BigInt = "123456789012345678901234567890".parse().unwrap();
+/// assert_eq!((&big * &big / &big).to_string(), "123456789012345678901234567890");
+/// assert_eq!((&big % 1000).to_string(), "890");
+/// assert_eq!(big.num_digits(), 30);
+/// assert_eq!(BigInt::from(1).checked_sub(&BigInt::from(2)), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BigInt {
+    /// Limbs in base 10^9, the least significant first, without leading zero
+    /// limbs: 0 has no limb at all.
+    limbs: Vec<u32>,
+}
+
+impl BigInt {
+    /// Create a new BigInt, starting at 0. To create a BigInt from an existing
+    /// number, use `BigInt::from()` instead.
+    pub fn new() -> Self {
+        Self { limbs: Vec::new() }
+    }
+
+    /// Create a new BigInt from the given number.
+    pub fn from(x: usize) -> Self {
+        let mut limbs = Vec::new();
+        let mut remain = x as u64;
+        while remain > 0 {
+            limbs.push((remain % BASE) as u32);
+            remain /= BASE;
+        }
+        Self { limbs }
+    }
+
+    /// Create a BigInt from its decimal digits, the least significant first.
+    fn from_digits(digits: &[usize]) -> Self {
+        let limbs = digits
+            .chunks(LIMB_DIGITS)
+            .map(|chunk| chunk.iter().rev().fold(0, |acc, &d| acc * 10 + d as u32))
+            .collect();
+        let mut x = Self { limbs };
+        x.trim();
+        x
+    }
+
+    /// Decimal digits of the number, the least significant first, like
+    /// `digits_of`.
+    pub fn digits(&self) -> Vec<usize> {
+        let mut digits = Vec::with_capacity(self.limbs.len() * LIMB_DIGITS);
+        for &limb in &self.limbs {
+            let mut remain = limb as usize;
+            for _ in 0..LIMB_DIGITS {
+                digits.push(remain % 10);
+                remain /= 10;
+            }
+        }
+        // The most significant limb is padded with zeros
+        while digits.len() > 1 && digits[digits.len() - 1] == 0 {
+            digits.pop();
+        }
+        if digits.is_empty() {
+            digits.push(0);
+        }
+        digits
+    }
+
+    /// Number of decimal digits of the number (1 for 0).
+    pub fn num_digits(&self) -> usize {
+        match self.limbs.last() {
+            None => 1,
+            Some(&top) => (self.limbs.len() - 1) * LIMB_DIGITS + top.ilog10() as usize + 1,
+        }
+    }
+
+    /// Create a copy of the current number, with the digits reversed.
+    pub fn reverse(&self) -> Self {
+        let n = self.num_digits();
+        let limbs = (0..n)
+            .step_by(LIMB_DIGITS)
+            .map(|start| {
+                // The k-th digit of the reversed number is the (n - 1 - k)-th one
+                (start..n.min(start + LIMB_DIGITS))
+                    .rev()
+                    .fold(0, |acc, k| acc * 10 + self.digit(n - 1 - k))
+            })
+            .collect();
+        let mut reversed = Self { limbs };
+        reversed.trim();
+        reversed
+    }
+
+    /// Function to check if the current number is a palindrome.
+    pub fn is_palindrome(&self) -> bool {
+        let n = self.num_digits();
+        (0..n / 2).all(|i| self.digit(i) == self.digit(n - 1 - i))
+    }
+
+    /// The i-th decimal digit, starting from the least significant one.
+    fn digit(&self, i: usize) -> u32 {
+        let limb = self.limbs.get(i / LIMB_DIGITS).copied().unwrap_or(0);
+        limb / 10u32.pow((i % LIMB_DIGITS) as u32) % 10
+    }
+
+    /// Check if the number is 0.
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// Subtraction, returning `None` if the result would be negative.
+    pub fn checked_sub(&self, x: &BigInt) -> Option<BigInt> {
+        if *self < *x {
+            return None;
+        }
+        let mut result = self.clone();
+        result.sub_limbs(x);
+        Some(result)
+    }
+
+    /// Quotient and remainder of the division by `x`, or `None` if `x` is 0.
+    pub fn checked_div_rem(&self, x: &BigInt) -> Option<(BigInt, BigInt)> {
+        match x.limbs.len() {
+            0 => None,
+            _ if *self < *x => Some((BigInt::new(), self.clone())),
+            1 => {
+                let (quotient, remainder) = self.div_rem_limb(x.limbs[0] as u64);
+                Some((quotient, BigInt::from(remainder as usize)))
+            }
+            _ => Some(self.long_div_rem(x)),
+        }
+    }
+
+    /// Division, returning `None` if `x` is 0.
+    pub fn checked_div(&self, x: &BigInt) -> Option<BigInt> {
+        self.checked_div_rem(x).map(|(q, _)| q)
+    }
+
+    /// Remainder, returning `None` if `x` is 0.
+    pub fn checked_rem(&self, x: &BigInt) -> Option<BigInt> {
+        self.checked_div_rem(x).map(|(_, r)| r)
+    }
+
+    /// Square of the number. This is faster than `&x * &y` with two different
+    /// numbers, since half of the products of limbs are the same.
+    pub fn square(&self) -> BigInt {
+        self.square_with_threshold(KARATSUBA_THRESHOLD)
+    }
+
+    /// Product with `x`, using Karatsuba's method while both operands have at
+    /// least `threshold` limbs (of 9 digits), and the schoolbook method below.
+    ///
+    /// The `*` operator uses a default threshold; a `threshold` of
+    /// `usize::MAX` always gives the schoolbook multiplication.
+    ///
+    /// # Examples
+    /// ```
+    /// use reuler::utils::BigInt;
+    ///
+    /// let x: BigInt = "9".repeat(1000).parse().unwrap();
+    /// let y: BigInt = "8".repeat(700).parse().unwrap();
+    /// assert_eq!(x.mul_with_threshold(&y, 4), x.mul_with_threshold(&y, usize::MAX));
+    /// assert_eq!(x.mul_with_threshold(&x, 4), x.square());
+    /// ```
+    pub fn mul_with_threshold(&self, x: &BigInt, threshold: usize) -> BigInt {
+        let mut result = BigInt {
+            limbs: karatsuba_mul(&self.limbs, &x.limbs, threshold),
+        };
+        result.trim();
+        result
+    }
+
+    /// Square of the number, with the same `threshold` as in
+    /// `mul_with_threshold`.
+    pub fn square_with_threshold(&self, threshold: usize) -> BigInt {
+        let mut result = BigInt {
+            limbs: karatsuba_square(&self.limbs, threshold),
+        };
+        result.trim();
+        result
+    }
+
+    /// Compute `self^exponent`, using exponentiation by squaring.
+    ///
+    /// # Examples
+    /// ```
+    /// use reuler::utils::BigInt;
+    ///
+    /// let x = BigInt::from(2).pow(100);
+    /// assert_eq!(x.to_string(), "1267650600228229401496703205376");
+    /// assert_eq!(BigInt::new().pow(0), BigInt::from(1));
+    /// ```
+    pub fn pow(&self, exponent: u32) -> BigInt {
+        // Go through the bits of the exponent, from the most significant one
+        let mut result = BigInt::from(1);
+        for bit in (0..u32::BITS - exponent.leading_zeros()).rev() {
+            result = result.square();
+            if (exponent >> bit) & 1 == 1 {
+                result = &result * self;
+            }
+        }
+        result
+    }
+
+    /// Compute `self^exponent mod modulus`.
+    ///
+    /// The exponent is read one decimal digit at a time, from the most
+    /// significant one: each step raises the result to the 10th power, then
+    /// multiplies it by `self^digit`. Every product is reduced right away, so
+    /// no intermediate value reaches `modulus²`.
+    ///
+    /// # Panics
+    /// Panics if `modulus` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use reuler::utils::BigInt;
+    ///
+    /// let modulus = BigInt::from(10).pow(10);
+    /// let x = BigInt::from(1000).pow_mod(&BigInt::from(1000), &modulus);
+    /// assert_eq!(x, BigInt::new());
+    /// let x = BigInt::from(3).pow_mod(&BigInt::from(1000), &modulus);
+    /// assert_eq!(x.to_string(), "2855220001");
+    /// ```
+    pub fn pow_mod(&self, exponent: &BigInt, modulus: &BigInt) -> BigInt {
+        let mul_mod = |a: &BigInt, b: &BigInt| &(a * b) % modulus;
+
+        // powers[d] = self^d mod modulus, for every decimal digit d
+        let base = self % modulus;
+        let mut powers = vec![&BigInt::from(1) % modulus];
+        for d in 1..10 {
+            powers.push(mul_mod(&powers[d - 1], &base));
+        }
+
+        let mut result = powers[0].clone();
+        for &d in exponent.digits().iter().rev() {
+            // result^10 = ((result²)² × result)²
+            let square = mul_mod(&result, &result);
+            let fifth = mul_mod(&mul_mod(&square, &square), &result);
+            result = mul_mod(&mul_mod(&fifth, &fifth), &powers[d]);
+        }
+        result
+    }
+
+    /// Compute `n!`.
+    ///
+    /// # Examples
+    /// ```
+    /// use reuler::utils::BigInt;
+    ///
+    /// assert_eq!(BigInt::factorial(0), BigInt::from(1));
+    /// assert_eq!(BigInt::factorial(25).to_string(), "15511210043330985984000000");
+    /// ```
+    pub fn factorial(n: usize) -> BigInt {
+        product(&(2..=n).collect::<Vec<usize>>())
+    }
+
+    /// Compute the binomial coefficient `(n k)`: the number of ways to choose
+    /// `k` elements among `n`, or 0 if `k > n`.
+    ///
+    /// With `k <= n - k`, the coefficient is `(n - k + 1)(n - k + 2)...n / k!`.
+    /// Instead of dividing, the prime factors of `k!` (given by Legendre's
+    /// formula) are removed from the factors of the numerator, which are then
+    /// multiplied. This takes `O(k)` memory, whatever the size of `n`.
+    ///
+    /// # Examples
+    /// ```
+    /// use reuler::utils::BigInt;
+    ///
+    /// assert_eq!(BigInt::binomial(5, 2), BigInt::from(10));
+    /// assert_eq!(BigInt::binomial(2, 5), BigInt::new());
+    /// assert_eq!(BigInt::binomial(100, 50).to_string(), "100891344545564193334812497256");
+    /// assert_eq!(BigInt::binomial(1_000_000_000, 2), BigInt::from(499_999_999_500_000_000));
+    /// ```
+    pub fn binomial(n: usize, k: usize) -> BigInt {
+        if k > n {
+            return BigInt::new();
+        }
+        let k = k.min(n - k);
+        let lo = n - k + 1;
+        let mut factors: Vec<usize> = (lo..=n).collect();
+
+        for p in &Sieve::new(k) {
+            // Exponent of p in k!
+            let mut exponent = 0;
+            let mut q = k;
+            while q > 0 {
+                q /= p;
+                exponent += q;
+            }
+
+            // Remove one p from each multiple of p in [lo, n], then one more
+            // from each multiple of p², etc... until there is none left to
+            // remove. The numerator is divisible by k!, so it always ends.
+            let mut p_i = p;
+            while exponent > 0 {
+                let mut multiple = lo.div_ceil(p_i).checked_mul(p_i);
+                while let Some(m) = multiple.filter(|&m| m <= n && exponent > 0) {
+                    factors[m - lo] /= p;
+                    exponent -= 1;
+                    multiple = m.checked_add(p_i);
+                }
+                p_i *= p;
+            }
+        }
+        product(&factors)
+    }
+
+    /// Remove the leading zero limbs.
+    fn trim(&mut self) {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+    }
+
+    /// Subtract `x` in place. `self` should be at least `x`.
+    fn sub_limbs(&mut self, x: &BigInt) {
+        sub_assign(&mut self.limbs, &x.limbs);
+        self.trim();
+    }
+
+    /// Quotient and remainder of the division by a single limb `d`.
+    fn div_rem_limb(&self, d: u64) -> (BigInt, u64) {
+        let mut limbs = vec![0; self.limbs.len()];
+        let mut remainder = 0;
+        for (i, &limb) in self.limbs.iter().enumerate().rev() {
+            let current = remainder * BASE + limb as u64;
+            limbs[i] = (current / d) as u32;
+            remainder = current % d;
+        }
+        let mut quotient = BigInt { limbs };
+        quotient.trim();
+        (quotient, remainder)
+    }
+
+    /// Long division by `x`, which has at least 2 limbs and is at most `self`.
+    ///
+    /// This is Knuth's algorithm D: both numbers are first scaled so that the
+    /// top limb of the divisor is at least `BASE / 2`. Then each limb of the
+    /// quotient estimated from the top limbs is off by at most 2, and is
+    /// corrected before the subtraction.
+    fn long_div_rem(&self, x: &BigInt) -> (BigInt, BigInt) {
+        let n = x.limbs.len();
+        let m = self.limbs.len() - n;
+        let scale = BASE / (x.limbs[n - 1] as u64 + 1);
+        let scaled = |limbs: &[u32]| {
+            let mut carry = 0;
+            let mut result: Vec<u64> = limbs
+                .iter()
+                .map(|&limb| {
+                    let current = limb as u64 * scale + carry;
+                    carry = current / BASE;
+                    current % BASE
+                })
+                .collect();
+            result.push(carry);
+            result
+        };
+        let mut u = scaled(&self.limbs);
+        let mut v = scaled(&x.limbs);
+        // The divisor can't grow past its number of limbs
+        v.pop();
+
+        let mut quotient = vec![0; m + 1];
+        for j in (0..=m).rev() {
+            // Estimate the quotient limb from the top limbs
+            let top = u[j + n] * BASE + u[j + n - 1];
+            let mut q = top / v[n - 1];
+            let mut r = top % v[n - 1];
+            while q >= BASE || q * v[n - 2] > r * BASE + u[j + n - 2] {
+                q -= 1;
+                r += v[n - 1];
+                if r >= BASE {
+                    break;
+                }
+            }
+
+            // Subtract q * v from the current part of u
+            let mut borrow = 0;
+            let mut carry = 0;
+            for i in 0..=n {
+                let product = q * v.get(i).copied().unwrap_or(0) + carry;
+                carry = product / BASE;
+                let subtracted = product % BASE + borrow;
+                if u[i + j] >= subtracted {
+                    u[i + j] -= subtracted;
+                    borrow = 0;
+                } else {
+                    u[i + j] += BASE - subtracted;
+                    borrow = 1;
+                }
+            }
+
+            // The estimate was still one too large : add v back
+            if borrow > 0 {
+                q -= 1;
+                let mut carry = 0;
+                for i in 0..=n {
+                    let sum = u[i + j] + v.get(i).copied().unwrap_or(0) + carry;
+                    u[i + j] = sum % BASE;
+                    carry = sum / BASE;
+                }
+            }
+            quotient[j] = q as u32;
+        }
+
+        let mut quotient = BigInt { limbs: quotient };
+        quotient.trim();
+        let mut remainder = BigInt {
+            limbs: u[..n].iter().map(|&limb| limb as u32).collect(),
+        };
+        remainder.trim();
+        (quotient, remainder.div_rem_limb(scale).0)
+    }
+}
+
+/// Sum of two slices of limbs.
+fn add_limbs(a: &[u32], b: &[u32]) -> Vec<u32> {
+    // Start from the longest number, and add the other one limb-by-limb
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut limbs = long.to_vec();
+
+    let mut carry_over = 0;
+    for (i, limb) in limbs.iter_mut().enumerate() {
+        if i >= short.len() && carry_over == 0 {
+            break;
+        }
+        let limb_result = *limb as u64 + short.get(i).copied().unwrap_or(0) as u64 + carry_over;
+        *limb = (limb_result % BASE) as u32;
+        carry_over = limb_result / BASE;
+    }
+
+    if carry_over != 0 {
+        // We have a carry over left, add a new limb
+        limbs.push(carry_over as u32);
+    }
+    limbs
+}
+
+/// Add `x`, shifted by `offset` limbs, to `limbs` in place. The result should
+/// fit in `limbs`.
+fn add_at(limbs: &mut [u32], x: &[u32], offset: usize) {
+    let mut carry_over = 0;
+    for (i, limb) in limbs[offset..].iter_mut().enumerate() {
+        if i >= x.len() && carry_over == 0 {
+            break;
+        }
+        let limb_result = *limb as u64 + x.get(i).copied().unwrap_or(0) as u64 + carry_over;
+        *limb = (limb_result % BASE) as u32;
+        carry_over = limb_result / BASE;
+    }
+}
+
+/// Subtract `x` from `limbs` in place. `limbs` should be at least `x`.
+fn sub_assign(limbs: &mut [u32], x: &[u32]) {
+    let mut borrow = 0;
+    for (i, limb) in limbs.iter_mut().enumerate() {
+        if i >= x.len() && borrow == 0 {
+            break;
+        }
+        let x_limb = x.get(i).copied().unwrap_or(0) + borrow;
+        if *limb >= x_limb {
+            *limb -= x_limb;
+            borrow = 0;
+        } else {
+            *limb += BASE as u32 - x_limb;
+            borrow = 1;
+        }
+    }
+}
+
+/// Product of the given factors.
+///
+/// The factors are multiplied as a balanced tree, so that the large products
+/// are between numbers of similar size, where Karatsuba's method helps.
+fn product(factors: &[usize]) -> BigInt {
+    if factors.len() <= 16 {
+        let mut result = BigInt::from(1);
+        for &x in factors {
+            result *= x;
+        }
+        return result;
+    }
+    let (left, right) = factors.split_at(factors.len() / 2);
+    &product(left) * &product(right)
+}
+
+/// Remove the leading zero limbs of a slice.
+fn trimmed(limbs: &[u32]) -> &[u32] {
+    let len = limbs
+        .iter()
+        .rposition(|&limb| limb != 0)
+        .map_or(0, |i| i + 1);
+    &limbs[..len]
+}
+
+/// Product of two slices of limbs, with the schoolbook method. The result has
+/// exactly `a.len() + b.len()` limbs, possibly with leading zeros.
+fn schoolbook_mul(a: &[u32], b: &[u32]) -> Vec<u32> {
+    // Propagate the carries one row at a time
+    let mut limbs = vec![0u64; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry_over = 0;
+        for (j, &y) in b.iter().enumerate() {
+            let limb_result = limbs[i + j] + x as u64 * y as u64 + carry_over;
+            limbs[i + j] = limb_result % BASE;
+            carry_over = limb_result / BASE;
+        }
+        limbs[i + b.len()] = carry_over;
+    }
+    limbs.into_iter().map(|limb| limb as u32).collect()
+}
+
+/// Square of a slice of limbs, with the schoolbook method. Same output as
+/// `schoolbook_mul(a, a)`.
+fn schoolbook_square(a: &[u32]) -> Vec<u32> {
+    // Every cross product a[i] a[j] with i != j appears twice in the square :
+    // compute them once for i < j, then double them
+    let n = a.len();
+    let mut limbs = vec![0u64; 2 * n];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry_over = 0;
+        for (j, &y) in a.iter().enumerate().skip(i + 1) {
+            let limb_result = limbs[i + j] + x as u64 * y as u64 + carry_over;
+            limbs[i + j] = limb_result % BASE;
+            carry_over = limb_result / BASE;
+        }
+        limbs[i + n] = carry_over;
+    }
+
+    // Double, and add the squares a[i]² at the position 2i
+    let mut carry_over = 0;
+    for (k, limb) in limbs.iter_mut().enumerate() {
+        let square = match k % 2 {
+            0 => a[k / 2] as u64 * a[k / 2] as u64,
+            _ => 0,
+        };
+        let limb_result = 2 * *limb + square + carry_over;
+        *limb = limb_result % BASE;
+        carry_over = limb_result / BASE;
+    }
+    limbs.into_iter().map(|limb| limb as u32).collect()
+}
+
+/// Product of two slices of limbs, with Karatsuba's method while both have
+/// at least `threshold` limbs. Same output as `schoolbook_mul`.
+///
+/// With `a = a1 B^m + a0` and `b = b1 B^m + b0`, the product is
+/// `z2 B^2m + z1 B^m + z0` where `z2 = a1 b1`, `z0 = a0 b0` and
+/// `z1 = (a0 + a1)(b0 + b1) - z2 - z0`: 3 half-size products instead of 4.
+fn karatsuba_mul(a: &[u32], b: &[u32], threshold: usize) -> Vec<u32> {
+    let (a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    if b.len() < threshold.max(2) {
+        return schoolbook_mul(a, b);
+    }
+
+    let mut limbs = vec![0; a.len() + b.len()];
+    if 2 * b.len() <= a.len() {
+        // Unbalanced operands : multiply b by slices of a of its own size
+        for (i, chunk) in a.chunks(b.len()).enumerate() {
+            let product = karatsuba_mul(chunk, b, threshold);
+            add_at(&mut limbs, trimmed(&product), i * b.len());
+        }
+        return limbs;
+    }
+
+    // Since b is more than half as long as a, b1 isn't empty
+    let m = a.len() / 2;
+    let (a0, a1) = a.split_at(m);
+    let (b0, b1) = b.split_at(m);
+    let z0 = karatsuba_mul(a0, b0, threshold);
+    let z2 = karatsuba_mul(a1, b1, threshold);
+    let mut z1 = karatsuba_mul(&add_limbs(a0, a1), &add_limbs(b0, b1), threshold);
+    sub_assign(&mut z1, trimmed(&z0));
+    sub_assign(&mut z1, trimmed(&z2));
+
+    limbs[..2 * m].copy_from_slice(&z0);
+    limbs[2 * m..].copy_from_slice(&z2);
+    add_at(&mut limbs, trimmed(&z1), m);
+    limbs
+}
+
+/// Square of a slice of limbs, with Karatsuba's method while it has at least
+/// `threshold` limbs. Same output as `schoolbook_square`.
+fn karatsuba_square(a: &[u32], threshold: usize) -> Vec<u32> {
+    if a.len() < threshold.max(2) {
+        return schoolbook_square(a);
+    }
+
+    let m = a.len() / 2;
+    let (a0, a1) = a.split_at(m);
+    let z0 = karatsuba_square(a0, threshold);
+    let z2 = karatsuba_square(a1, threshold);
+    let mut z1 = karatsuba_square(&add_limbs(a0, a1), threshold);
+    sub_assign(&mut z1, trimmed(&z0));
+    sub_assign(&mut z1, trimmed(&z2));
+
+    let mut limbs = vec![0; 2 * a.len()];
+    limbs[..2 * m].copy_from_slice(&z0);
+    limbs[2 * m..].copy_from_slice(&z2);
+    add_at(&mut limbs, trimmed(&z1), m);
+    limbs
+}
+
+impl fmt::Display for BigInt {
+    /// Write the number in decimal.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Some((top, rest)) = self.limbs.split_last() else {
+            return write!(f, "0");
+        };
+        write!(f, "{top}")?;
+        for limb in rest.iter().rev() {
+            write!(f, "{limb:09}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for BigInt {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = Error;
+
+    /// Parse a BigInt from its decimal representation.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = s
+            .chars()
+            .rev()
+            .map(|c| c.to_digit(10).map(|d| d as usize))
+            .collect::<Option<Vec<usize>>>()
+            .filter(|digits| !digits.is_empty())
+            .ok_or_else(|| Error::MalformedInput(format!("`{s}` is not a valid number.")))?;
+
+        Ok(BigInt::from_digits(&digits))
+    }
+}
+
+impl Ord for BigInt {
+    /// Compare two BigInt by their value.
+    fn cmp(&self, other: &Self) -> Ordering {
+        // More limbs means a bigger number, otherwise compare from the most
+        // significant limb
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl ops::Add<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    /// Overload the addition for BigInt.
+    fn add(self, x: &BigInt) -> Self::Output {
+        BigInt {
+            limbs: add_limbs(&self.limbs, &x.limbs),
+        }
+    }
+}
+
+impl ops::Sub<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    /// Overload the subtraction for BigInt.
+    ///
+    /// # Panics
+    /// Panics if the result would be negative.
+    fn sub(self, x: &BigInt) -> Self::Output {
+        self.checked_sub(x)
+            .expect("attempt to subtract with overflow")
+    }
+}
+
+impl ops::Mul<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    /// Overload the multiplication for BigInt.
+    fn mul(self, x: &BigInt) -> Self::Output {
+        if self == x {
+            self.square()
+        } else {
+            self.mul_with_threshold(x, KARATSUBA_THRESHOLD)
+        }
+    }
+}
+
+impl ops::Div<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    /// Overload the division for BigInt.
+    ///
+    /// # Panics
+    /// Panics if `x` is 0.
+    fn div(self, x: &BigInt) -> Self::Output {
+        self.checked_div(x).expect("attempt to divide by zero")
+    }
+}
+
+impl ops::Rem<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    /// Overload the remainder for BigInt.
+    ///
+    /// # Panics
+    /// Panics if `x` is 0.
+    fn rem(self, x: &BigInt) -> Self::Output {
+        self.checked_rem(x)
+            .expect("attempt to calculate the remainder with a divisor of zero")
+    }
+}
+
+impl ops::MulAssign<usize> for BigInt {
+    /// Overload the multiplication-assign for BigInt.
+    fn mul_assign(&mut self, x: usize) {
+        if x as u64 >= BASE {
+            // The products of limbs could overflow, use the full multiplication
+            *self = &*self * &BigInt::from(x);
+            return;
+        }
+
+        // Multiply each limb, one-by-one
+        let mut carry_over = 0;
+        for limb in self.limbs.iter_mut() {
+            let limb_result = *limb as u64 * x as u64 + carry_over;
+            *limb = (limb_result % BASE) as u32;
+            carry_over = limb_result / BASE;
+        }
+
+        if carry_over != 0 {
+            // We have a carry over left, add a new limb
+            self.limbs.push(carry_over as u32);
+        }
+
+        // If we multiply by 0, all the limbs are now 0
+        self.trim();
+    }
+}
+
+impl ops::Mul<usize> for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, x: usize) -> Self::Output {
+        let mut result = self.clone();
+        result *= x;
+        result
+    }
+}
+
+impl ops::Mul<usize> for BigInt {
+    type Output = BigInt;
+
+    fn mul(mut self, x: usize) -> Self::Output {
+        self *= x;
+        self
+    }
+}
+
+/// Implement an operator on every combination of values and references,
+/// along with its assign form, from its implementation on references.
+macro_rules! forward_binop {
+    ($t:ty, $trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident) => {
+        impl ops::$trait<$t> for $t {
+            type Output = $t;
+
+            fn $method(self, x: $t) -> Self::Output {
+                ops::$trait::$method(&self, &x)
+            }
+        }
+
+        impl ops::$trait<&$t> for $t {
+            type Output = $t;
+
+            fn $method(self, x: &$t) -> Self::Output {
+                ops::$trait::$method(&self, x)
+            }
+        }
+
+        impl ops::$trait<$t> for &$t {
+            type Output = $t;
+
+            fn $method(self, x: $t) -> Self::Output {
+                ops::$trait::$method(self, &x)
+            }
+        }
+
+        impl ops::$assign_trait<&$t> for $t {
+            fn $assign_method(&mut self, x: &$t) {
+                *self = ops::$trait::$method(&*self, x);
+            }
+        }
+
+        impl ops::$assign_trait<$t> for $t {
+            fn $assign_method(&mut self, x: $t) {
+                *self = ops::$trait::$method(&*self, &x);
+            }
+        }
+    };
+}
+
+/// Implement an operator between a big integer and a primitive integer, along
+/// with its assign form, by converting the primitive integer.
+macro_rules! primitive_binop {
+    ($t:ty, $prim:ty, $trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident) => {
+        impl ops::$trait<$prim> for &$t {
+            type Output = $t;
+
+            fn $method(self, x: $prim) -> Self::Output {
+                ops::$trait::$method(self, &<$t>::from(x))
+            }
+        }
+
+        impl ops::$trait<$prim> for $t {
+            type Output = $t;
+
+            fn $method(self, x: $prim) -> Self::Output {
+                ops::$trait::$method(&self, &<$t>::from(x))
+            }
+        }
+
+        impl ops::$assign_trait<$prim> for $t {
+            fn $assign_method(&mut self, x: $prim) {
+                *self = ops::$trait::$method(&*self, &<$t>::from(x));
+            }
+        }
+    };
+}
+
+pub(super) use {forward_binop, primitive_binop};
+
+forward_binop!(BigInt, Add, add, AddAssign, add_assign);
+forward_binop!(BigInt, Sub, sub, SubAssign, sub_assign);
+forward_binop!(BigInt, Mul, mul, MulAssign, mul_assign);
+forward_binop!(BigInt, Div, div, DivAssign, div_assign);
+forward_binop!(BigInt, Rem, rem, RemAssign, rem_assign);
+
+primitive_binop!(BigInt, usize, Add, add, AddAssign, add_assign);
+primitive_binop!(BigInt, usize, Sub, sub, SubAssign, sub_assign);
+primitive_binop!(BigInt, usize, Div, div, DivAssign, div_assign);
+primitive_binop!(BigInt, usize, Rem, rem, RemAssign, rem_assign);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Values covering small numbers, carries and the whole `u64` range.
+    const VALUES: [u128; 14] = [
+        0,
+        1,
+        2,
+        9,
+        10,
+        99,
+        100,
+        1234,
+        999_999_999,
+        1_000_000_000,
+        12_345_678_901_234_567_890,
+        u64::MAX as u128,
+        1 << 64,
+        100_000_000_000_000_000_000_000_000_000,
+    ];
+
+    fn big(x: u128) -> BigInt {
+        x.to_string().parse().unwrap()
+    }
+
+    /// Xorshift generator, for reproducible random operands.
+    fn xorshift(state: &mut u64) -> u64 {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        *state
+    }
+
+    /// Random number with up to `len` limbs.
+    fn random(state: &mut u64, len: usize) -> BigInt {
+        // Favour the extreme limbs, where the carries and borrows happen
+        let limbs = (0..len)
+            .map(|_| match xorshift(state) % 4 {
+                0 => 0,
+                1 => BASE as u32 - 1,
+                _ => (xorshift(state) % BASE) as u32,
+            })
+            .collect();
+        let mut x = BigInt { limbs };
+        x.trim();
+        x
+    }
+
+    #[test]
+    fn test_bigint_addition_same_number_of_digits() {
+        assert_eq!(
+            &BigInt::from(125) + &BigInt::from(988),
+            BigInt::from(125 + 988)
+        );
+    }
+
+    #[test]
+    fn test_bigint_addition_more_digits() {
+        assert_eq!(&BigInt::from(125) + &BigInt::from(7), BigInt::from(125 + 7));
+    }
+
+    #[test]
+    fn test_bigint_addition_less_digits() {
+        assert_eq!(
+            &BigInt::from(56) + &BigInt::from(4852),
+            BigInt::from(56 + 4852)
+        );
+    }
+
+    #[test]
+    fn test_bigint_new() {
+        assert_eq!(BigInt::new(), BigInt::from(0));
+    }
+
+    #[test]
+    fn test_bigint_multiplication_1() {
+        let mut big_int = BigInt::from(75);
+        big_int *= 3;
+        assert_eq!(big_int, BigInt::from(75 * 3));
+    }
+
+    #[test]
+    fn test_bigint_multiplication_2() {
+        let mut big_int = BigInt::from(3);
+        big_int *= 75;
+        assert_eq!(big_int, BigInt::from(3 * 75));
+    }
+
+    #[test]
+    fn test_bigint_multiplication_by_zero() {
+        let mut big_int = BigInt::from(9888);
+        big_int *= 0;
+        assert_eq!(big_int, BigInt::new());
+    }
+
+    #[test]
+    fn test_bigint_ordering() {
+        assert!(BigInt::from(99) < BigInt::from(100));
+        assert!(BigInt::from(512) > BigInt::from(256));
+        assert!(BigInt::from(256) < BigInt::from(512));
+        assert_eq!(BigInt::from(7).cmp(&BigInt::from(7)), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_matches_u128() {
+        for a in VALUES {
+            for b in VALUES {
+                let (x, y) = (big(a), big(b));
+                if let Some(sum) = a.checked_add(b) {
+                    assert_eq!(&x + &y, big(sum), "{a} + {b}");
+                }
+                assert_eq!(x.checked_sub(&y), a.checked_sub(b).map(big), "{a} - {b}");
+                if let Some(product) = a.checked_mul(b) {
+                    assert_eq!(&x * &y, big(product), "{a} * {b}");
+                }
+                assert_eq!(x.checked_div(&y), a.checked_div(b).map(big), "{a} / {b}");
+                assert_eq!(x.checked_rem(&y), a.checked_rem(b).map(big), "{a} % {b}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_usize_operands() {
+        let x = big(12_345_678_901_234_567_890);
+        assert_eq!(&x + 10, big(12_345_678_901_234_567_900));
+        assert_eq!(&x - 890, big(12_345_678_901_234_567_000));
+        assert_eq!(&x * 1000, big(12_345_678_901_234_567_890_000));
+        assert_eq!(&x / 1000, big(12_345_678_901_234_567));
+        assert_eq!(&x % 1000, big(890));
+    }
+
+    #[test]
+    fn test_assign_operators() {
+        let mut x = BigInt::from(100);
+        x += BigInt::from(20);
+        x -= &BigInt::from(5);
+        x *= &BigInt::from(4);
+        x /= 3;
+        x %= BigInt::from(100);
+        assert_eq!(x, BigInt::from((100 + 20 - 5) * 4 / 3 % 100));
+    }
+
+    #[test]
+    fn test_owned_operands() {
+        let x = BigInt::from(7) * BigInt::from(6) - BigInt::from(2);
+        assert_eq!(
+            x / BigInt::from(4) + BigInt::from(1) % BigInt::from(1),
+            BigInt::from(10)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_negative_result() {
+        let _ = BigInt::from(1) - 2;
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_division_by_zero() {
+        let _ = BigInt::from(1) / 0;
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("000123".parse::<BigInt>().unwrap(), BigInt::from(123));
+        assert_eq!("0".parse::<BigInt>().unwrap(), BigInt::new());
+        assert!("".parse::<BigInt>().is_err());
+        assert!("12a".parse::<BigInt>().is_err());
+        assert!("-1".parse::<BigInt>().is_err());
+    }
+
+    /// Multiply a number stored with one decimal digit per `usize`, the least
+    /// significant first, by `x`: this is how BigInt used to be stored.
+    fn mul_decimal_digits(digits: &mut Vec<usize>, x: usize) {
+        let mut carry_over = 0;
+        for d in digits.iter_mut() {
+            let digit_result = *d * x + carry_over;
+            *d = digit_result % 10;
+            carry_over = digit_result / 10;
+        }
+        while carry_over != 0 {
+            digits.push(carry_over % 10);
+            carry_over /= 10;
+        }
+    }
+
+    /// Median runtime of `f` over the given number of runs.
+    fn median_time<T>(runs: usize, f: impl Fn() -> T) -> std::time::Duration {
+        let mut times: Vec<_> = (0..runs)
+            .map(|_| {
+                let start = std::time::Instant::now();
+                std::hint::black_box(f());
+                start.elapsed()
+            })
+            .collect();
+        times.sort();
+        times[runs / 2]
+    }
+
+    #[test]
+    #[ignore = "timing, run with `cargo test --release -- --ignored --nocapture`"]
+    fn bench_limbs_against_decimal_digits() {
+        let products: [(&str, Vec<usize>); 2] =
+            [("2^1000", vec![2; 1000]), ("100!", (2..=100).collect())];
+        for (name, factors) in products {
+            let with_digits = || {
+                let mut digits = vec![1];
+                for &x in &factors {
+                    mul_decimal_digits(&mut digits, x);
+                }
+                digits
+            };
+            let with_limbs = || {
+                let mut x = BigInt::from(1);
+                for &factor in &factors {
+                    x *= factor;
+                }
+                x
+            };
+            assert_eq!(with_limbs().digits(), with_digits(), "{name}");
+
+            let digits_time = median_time(1000, with_digits);
+            let limbs_time = median_time(1000, with_limbs);
+            println!(
+                "{name} : {digits_time:?} with decimal digits, {limbs_time:?} with limbs ({:.1}x faster)",
+                digits_time.as_secs_f64() / limbs_time.as_secs_f64()
+            );
+        }
+    }
+
+    #[test]
+    fn test_reverse() {
+        assert_eq!(BigInt::from(1230).reverse(), BigInt::from(321));
+        assert_eq!(
+            big(1_000_000_000_000_000_002).reverse(),
+            big(2_000_000_000_000_000_001)
+        );
+        assert!(big(12_345_678_900_987_654_321).is_palindrome());
+        assert!(!big(12_345_678_900_987_654_320).is_palindrome());
+    }
+
+    #[test]
+    fn test_digits() {
+        for x in VALUES {
+            let expected: Vec<usize> = x
+                .to_string()
+                .chars()
+                .rev()
+                .map(|c| c.to_digit(10).unwrap() as usize)
+                .collect();
+            assert_eq!(big(x).digits(), expected, "{x}");
+            assert_eq!(big(x).num_digits(), expected.len(), "{x}");
+        }
+    }
+
+    #[test]
+    fn test_display_pads_inner_limbs() {
+        for x in VALUES {
+            assert_eq!(big(x).to_string(), x.to_string());
+        }
+        assert_eq!(
+            big(5_000_000_000_000_000_007).to_string(),
+            "5000000000000000007"
+        );
+    }
+
+    #[test]
+    fn test_karatsuba_matches_schoolbook() {
+        let mut state = 0x2545_f491_4f6c_dd1d;
+        for _ in 0..200 {
+            let (x_len, y_len) = (xorshift(&mut state) % 150, xorshift(&mut state) % 150);
+            let x = random(&mut state, x_len as usize);
+            let y = random(&mut state, y_len as usize);
+            let expected = x.mul_with_threshold(&y, usize::MAX);
+            for threshold in [0, 2, 3, 8, KARATSUBA_THRESHOLD] {
+                assert_eq!(x.mul_with_threshold(&y, threshold), expected, "{x} * {y}");
+            }
+            assert_eq!(&x * &y, expected, "{x} * {y}");
+        }
+    }
+
+    #[test]
+    fn test_square_matches_schoolbook() {
+        let mut state = 0x9e37_79b9_7f4a_7c15;
+        for len in 0..150 {
+            let x = random(&mut state, len);
+            let expected = x.mul_with_threshold(&x, usize::MAX);
+            for threshold in [0, 2, 3, 8, KARATSUBA_THRESHOLD, usize::MAX] {
+                assert_eq!(x.square_with_threshold(threshold), expected, "{x}²");
+            }
+            assert_eq!(&x * &x, expected, "{x}²");
+        }
+    }
+
+    #[test]
+    fn test_large_square() {
+        // (10^n - 1)² = 99..9800..01 and (10^n - 1) 10^n = 99..900..0
+        let n = 5000;
+        let x: BigInt = "9".repeat(n).parse().unwrap();
+        let expected = format!("{}8{}1", "9".repeat(n - 1), "0".repeat(n - 1));
+        assert_eq!(x.square().to_string(), expected);
+        let expected = format!("{}{}", "9".repeat(n), "0".repeat(n));
+        assert_eq!((&x * &(&x + 1)).to_string(), expected);
+    }
+
+    #[test]
+    fn test_pow() {
+        for base in [0, 1, 2, 3, 10, 999_999_999, 1_000_000_000, u64::MAX as u128] {
+            for exponent in 0..40 {
+                if let Some(expected) = base.checked_pow(exponent) {
+                    assert_eq!(big(base).pow(exponent), big(expected), "{base}^{exponent}");
+                }
+            }
+        }
+
+        let mut expected = BigInt::from(1);
+        for exponent in 0..300 {
+            assert_eq!(BigInt::from(7).pow(exponent), expected, "7^{exponent}");
+            expected *= 7;
+        }
+    }
+
+    #[test]
+    fn test_pow_mod() {
+        let mut state = 0x853c_49e6_748f_ea9b;
+        for _ in 0..200 {
+            let base = xorshift(&mut state);
+            let exponent = xorshift(&mut state) >> (xorshift(&mut state) % 64);
+            let modulus = (xorshift(&mut state) >> (xorshift(&mut state) % 64)).max(1);
+            let expected = crate::utils::mod_pow(base, exponent, modulus);
+            assert_eq!(
+                big(base as u128).pow_mod(&big(exponent as u128), &big(modulus as u128)),
+                big(expected as u128),
+                "{base}^{exponent} % {modulus}"
+            );
+        }
+
+        // Larger than any primitive integer
+        let x: BigInt = "123456789".repeat(10).parse().unwrap();
+        let modulus: BigInt = "987654321".repeat(5).parse().unwrap();
+        assert_eq!(
+            x.pow_mod(&BigInt::from(25), &modulus),
+            &x.pow(25) % &modulus
+        );
+        assert_eq!(x.pow_mod(&BigInt::new(), &BigInt::from(1)), BigInt::new());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_pow_mod_by_zero() {
+        BigInt::from(2).pow_mod(&BigInt::from(3), &BigInt::new());
+    }
+
+    #[test]
+    fn test_factorial() {
+        let mut expected = BigInt::from(1);
+        for n in 0..500 {
+            if n > 0 {
+                expected *= n;
+            }
+            assert_eq!(BigInt::factorial(n), expected, "{n}!");
+        }
+    }
+
+    #[test]
+    fn test_binomial() {
+        // Pascal's triangle
+        let mut row = vec![BigInt::from(1)];
+        for n in 0..150 {
+            for (k, expected) in row.iter().enumerate() {
+                assert_eq!(BigInt::binomial(n, k), *expected, "({n} {k})");
+            }
+            assert_eq!(BigInt::binomial(n, n + 1), BigInt::new(), "({n} {})", n + 1);
+
+            let mut next = vec![BigInt::from(1)];
+            next.extend(row.windows(2).map(|pair| &pair[0] + &pair[1]));
+            next.push(BigInt::from(1));
+            row = next;
+        }
+
+        let n = usize::MAX;
+        assert_eq!(BigInt::binomial(n, 1), BigInt::from(n));
+        assert_eq!(BigInt::binomial(n, n - 1), BigInt::from(n));
+        assert_eq!(
+            BigInt::binomial(n, 2),
+            BigInt::from(n) * BigInt::from(n - 1) / 2
+        );
+        assert_eq!(
+            BigInt::binomial(n, 3),
+            BigInt::from(n) * BigInt::from(n - 1) * BigInt::from(n - 2) / 6
+        );
+
+        let window = (100_000 - 29..=100_000).fold(BigInt::from(1), |x, m| x * m);
+        assert_eq!(
+            BigInt::binomial(100_000, 30) * BigInt::factorial(30),
+            window
+        );
+
+        for (n, k) in [(1000, 1), (1000, 333), (2500, 1250)] {
+            assert_eq!(
+                BigInt::binomial(n, k) * BigInt::factorial(k) * BigInt::factorial(n - k),
+                BigInt::factorial(n),
+                "({n} {k})"
+            );
+        }
+    }
+
+    #[test]
+    fn test_large_division() {
+        // Limbs close to 0, to BASE / 2 and to BASE stress the corrections of
+        // the quotient estimates
+        let numbers: Vec<BigInt> = [
+            "1000000000000000000000000000000000001",
+            "999999999999999999999999999999999999999999999",
+            "500000000000000000000000000000000000000000001",
+            "499999999999999999500000000000000000000000000",
+            "123456789123456789123456789123456789123456789123456789",
+            "100000000500000000",
+            "999999999000000000",
+            "500000000999999999",
+        ]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+
+        for a in &numbers {
+            for b in &numbers {
+                for c in &numbers {
+                    let x = &(a * b) + c;
+                    let (q, r) = x.checked_div_rem(b).unwrap();
+                    assert!(r < *b, "{x} % {b}");
+                    assert_eq!(&(&q * b) + &r, x, "{x} / {b}");
+                    assert_eq!(&(a * b) / b, *a, "{a} * {b} / {b}");
+                }
+            }
+        }
+    }
+}
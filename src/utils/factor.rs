@@ -0,0 +1,187 @@
+use super::{gcf, miller_rabin, mod_mul};
+
+/// Factors below this are found by trial division, larger ones with Pollard's
+/// rho.
+const TRIAL_DIVISION_LIMIT: usize = 1 << 10;
+
+/// Compute the prime factorization of the given number, as a list of
+/// `(prime, exponent)` sorted by prime.
+///
+/// Small factors are found by trial division, and the remaining part is split
+/// with Pollard's rho until every factor passes the Miller–Rabin test, so any
+/// `usize` is factorized in a few milliseconds.
+///
+/// # Panics
+/// Panics if the number is 0.
+///
+/// # Examples
+/// ```
+/// use reuler::utils::factor;
+///
+/// assert_eq!(factor(1), vec![]);
+/// assert_eq!(factor(360), vec![(2, 3), (3, 2), (5, 1)]);
+/// assert_eq!(factor(600851475143), vec![(71, 1), (839, 1), (1471, 1), (6857, 1)]);
+/// ```
+pub fn factor(n: usize) -> Vec<(usize, u32)> {
+    assert!(n > 0, "0 has no prime factorization.");
+
+    let mut factors = Vec::new();
+    let mut n = n;
+
+    // Trial division by the small factors
+    let mut d = 2;
+    while d < TRIAL_DIVISION_LIMIT && d * d <= n {
+        let mut exponent = 0;
+        while n.is_multiple_of(d) {
+            n /= d;
+            exponent += 1;
+        }
+        if exponent > 0 {
+            factors.push((d, exponent));
+        }
+        d += if d == 2 { 1 } else { 2 };
+    }
+    if n == 1 {
+        return factors;
+    }
+    if d * d > n {
+        // No factor below sqrt(n) : n is prime
+        factors.push((n, 1));
+        return factors;
+    }
+
+    // Split the rest with Pollard's rho
+    let mut large = Vec::new();
+    let mut stack = vec![n];
+    while let Some(m) = stack.pop() {
+        if miller_rabin(m as u64) {
+            large.push(m);
+        } else {
+            let d = pollard_rho(m);
+            stack.push(d);
+            stack.push(m / d);
+        }
+    }
+    large.sort_unstable();
+    for p in large {
+        match factors.last_mut() {
+            Some((q, exponent)) if *q == p => *exponent += 1,
+            _ => factors.push((p, 1)),
+        }
+    }
+    factors
+}
+
+/// Find a non-trivial divisor of the given composite number, with Brent's
+/// variant of Pollard's rho.
+fn pollard_rho(n: usize) -> usize {
+    // Number of steps between two gcd computations
+    const BATCH: usize = 128;
+
+    let m = n as u64;
+    for c in 1.. {
+        // Pseudo-random sequence x -> x² + c (mod n)
+        let f = |x: u64| {
+            let x = mod_mul(x, x, m);
+            if x >= m - c {
+                x - (m - c)
+            } else {
+                x + c
+            }
+        };
+
+        let (mut x, mut y, mut ys) = (0, 2, 2);
+        let (mut q, mut g) = (1, 1);
+        let mut r = 1;
+        while g == 1 {
+            x = y;
+            for _ in 0..r {
+                y = f(y);
+            }
+            let mut k = 0;
+            while k < r && g == 1 {
+                ys = y;
+                for _ in 0..BATCH.min(r - k) {
+                    y = f(y);
+                    q = mod_mul(q, x.abs_diff(y), m);
+                }
+                g = gcf(q as usize, n);
+                k += BATCH;
+            }
+            r *= 2;
+        }
+
+        // The batch overshot : go back step by step
+        if g == n {
+            loop {
+                ys = f(ys);
+                g = gcf(x.abs_diff(ys) as usize, n);
+                if g > 1 {
+                    break;
+                }
+            }
+        }
+        if g != n {
+            return g;
+        }
+    }
+    unreachable!()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Multiply the factors back.
+    fn product(factors: &[(usize, u32)]) -> usize {
+        factors.iter().map(|&(p, e)| p.pow(e)).product()
+    }
+
+    #[test]
+    fn test_small_numbers() {
+        for n in 1..10_000 {
+            let factors = factor(n);
+            assert_eq!(product(&factors), n, "{n}");
+            assert!(factors.iter().all(|&(p, _)| miller_rabin(p as u64)), "{n}");
+            assert!(factors.windows(2).all(|w| w[0].0 < w[1].0), "{n}");
+        }
+    }
+
+    #[test]
+    fn test_large_factors() {
+        assert_eq!(factor(1_000_000_007), vec![(1_000_000_007, 1)]);
+        assert_eq!(
+            factor(4_294_967_291 * 4_294_967_279),
+            vec![(4_294_967_279, 1), (4_294_967_291, 1)]
+        );
+        assert_eq!(
+            factor(1_000_003 * 1_000_003 * 1009),
+            vec![(1009, 1), (1_000_003, 2)]
+        );
+        assert_eq!(factor(1 << 63), vec![(2, 63)]);
+        assert_eq!(
+            factor(usize::MAX),
+            vec![
+                (3, 1),
+                (5, 1),
+                (17, 1),
+                (257, 1),
+                (641, 1),
+                (65_537, 1),
+                (6_700_417, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_prime_powers() {
+        assert_eq!(factor(3_486_784_401), vec![(3, 20)]);
+        assert_eq!(factor(999_999_937 * 999_999_937), vec![(999_999_937, 2)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_zero() {
+        factor(0);
+    }
+}
@@ -0,0 +1,399 @@
+//! Figurate numbers: polygonal, centered polygonal and pyramidal numbers.
+//!
+//! Polygonal numbers are indexed from 1 (`polygonal(s, 1) = 1`), centered
+//! polygonal numbers from 0 (`centered_polygonal(s, 0) = 1`). The order `s` is
+//! the number of sides (3 for triangle numbers, 4 for squares, etc...) and
+//! should be at least 3.
+//!
+//! Membership tests and index recovery are computed exactly in integers. The
+//! `checked_*` generators return `None` when the number doesn't fit in a
+//! `usize`, the others panic.
+//!
+//! # Examples
+//! ```
+//! use reuler::utils::figurate;
+//!
+//! assert_eq!(figurate::pentagonal(4), 22);
+//! assert_eq!(figurate::which_pentagonal(22), Some(4));
+//! assert!(figurate::is_hexagonal(40755));
+//! assert!(!figurate::is_triangle(40756));
+//! assert_eq!(figurate::checked_polygonal(3, 1 << 33), None);
+//!
+//! let octagonals: Vec<usize> = figurate::polygonals(8).take(4).collect();
+//! assert_eq!(octagonals, vec![1, 8, 21, 40]);
+//! ```
+
+use super::isqrt;
+
+/// Check that the order of a figurate number is valid.
+fn check_order(s: usize) {
+    assert!(s >= 3, "A polygon has at least 3 sides (given : {s}).");
+}
+
+/// Binary search of the index `n < hi` such that `f(n) = x`, if there is one.
+///
+/// `f` should be increasing, and return `None` when its value overflows,
+/// which is then considered above `x`.
+fn search_index(x: usize, hi: u128, f: impl Fn(u128) -> Option<u128>) -> Option<usize> {
+    let x = x as u128;
+    let mut lo = 0;
+    let mut hi = hi;
+    while lo < hi {
+        let mid = (lo + hi) / 2;
+        if f(mid).is_some_and(|value| value < x) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    (f(lo) == Some(x)).then_some(lo as usize)
+}
+
+/// The n-th s-gonal number, or `None` if it doesn't fit in a `u128`.
+fn polygonal_u128(s: u128, n: u128) -> Option<u128> {
+    match n {
+        0 => Some(0),
+        // Written to stay in unsigned integers when s = 3
+        _ => n.checked_mul((s - 2) * (n - 1) + 2).map(|v| v / 2),
+    }
+}
+
+/// The n-th s-gonal pyramidal number, or `None` if it doesn't fit in a
+/// `u128`.
+fn pyramidal_u128(s: u128, n: u128) -> Option<u128> {
+    match n {
+        0 => Some(0),
+        // Written to stay in unsigned integers when s < 5
+        _ => (n * (n + 1))
+            .checked_mul((s - 2) * (n - 1) + 3)
+            .map(|v| v / 6),
+    }
+}
+
+/// The n-th s-gonal number: `((s - 2)n² - (s - 4)n) / 2`, or `None` if it
+/// doesn't fit in a `usize`.
+///
+/// # Panics
+/// Panics if `s` is below 3.
+pub fn checked_polygonal(s: usize, n: usize) -> Option<usize> {
+    check_order(s);
+    polygonal_u128(s as u128, n as u128).and_then(|v| usize::try_from(v).ok())
+}
+
+/// The n-th s-gonal number: `((s - 2)n² - (s - 4)n) / 2`.
+///
+/// # Panics
+/// Panics if `s` is below 3, or if the number doesn't fit in a `usize`.
+pub fn polygonal(s: usize, n: usize) -> usize {
+    checked_polygonal(s, n).expect("The polygonal number doesn't fit in a `usize`.")
+}
+
+/// Index of the given s-gonal number: `n` such that `polygonal(s, n) = x`, if
+/// there is one.
+///
+/// # Panics
+/// Panics if `s` is below 3.
+pub fn which_polygonal(s: usize, x: usize) -> Option<usize> {
+    check_order(s);
+    if x == 0 {
+        return Some(0);
+    }
+    // Solve (s - 2)n² - (s - 4)n - 2x = 0 :
+    // n = (s - 4 + sqrt((s - 4)² + 8(s - 2)x)) / 2(s - 2)
+    let (s, x) = (s as u128, x as u128);
+    let delta = (8 * (s - 2))
+        .checked_mul(x)
+        .and_then(|d| d.checked_add(s.abs_diff(4).pow(2)));
+    let Some(delta) = delta.and_then(|d| usize::try_from(d).ok()) else {
+        // Only for large s or x : search the index instead. Polygonal
+        // numbers are at least the triangle numbers, so the index of any
+        // usize is below 2^33
+        return search_index(x as usize, 1 << 33, |n| polygonal_u128(s, n));
+    };
+    let root = isqrt(delta) as u128;
+    if root * root != delta as u128 {
+        return None;
+    }
+    let numerator = root + s - 4;
+    let denominator = 2 * (s - 2);
+    numerator
+        .is_multiple_of(denominator)
+        .then(|| (numerator / denominator) as usize)
+}
+
+/// Check if the given number is an s-gonal number.
+///
+/// # Panics
+/// Panics if `s` is below 3.
+pub fn is_polygonal(s: usize, x: usize) -> bool {
+    which_polygonal(s, x).is_some()
+}
+
+/// Iterate over the s-gonal numbers, starting at 1, as long as they fit in a
+/// `usize`.
+///
+/// # Panics
+/// Panics if `s` is below 3.
+pub fn polygonals(s: usize) -> impl Iterator<Item = usize> {
+    check_order(s);
+    (1..).map_while(move |n| checked_polygonal(s, n))
+}
+
+/// The n-th triangle number: `n(n + 1) / 2`.
+pub fn triangle(n: usize) -> usize {
+    polygonal(3, n)
+}
+
+/// Index of the given triangle number, if it is one.
+pub fn which_triangle(x: usize) -> Option<usize> {
+    which_polygonal(3, x)
+}
+
+/// Check if the given number is a triangle number.
+pub fn is_triangle(x: usize) -> bool {
+    is_polygonal(3, x)
+}
+
+/// The n-th pentagonal number: `n(3n - 1) / 2`.
+pub fn pentagonal(n: usize) -> usize {
+    polygonal(5, n)
+}
+
+/// Index of the given pentagonal number, if it is one.
+pub fn which_pentagonal(x: usize) -> Option<usize> {
+    which_polygonal(5, x)
+}
+
+/// Check if the given number is a pentagonal number.
+pub fn is_pentagonal(x: usize) -> bool {
+    is_polygonal(5, x)
+}
+
+/// The n-th hexagonal number: `n(2n - 1)`.
+pub fn hexagonal(n: usize) -> usize {
+    polygonal(6, n)
+}
+
+/// Index of the given hexagonal number, if it is one.
+pub fn which_hexagonal(x: usize) -> Option<usize> {
+    which_polygonal(6, x)
+}
+
+/// Check if the given number is a hexagonal number.
+pub fn is_hexagonal(x: usize) -> bool {
+    is_polygonal(6, x)
+}
+
+/// The n-th centered s-gonal number: `s × n(n + 1) / 2 + 1`, or `None` if it
+/// doesn't fit in a `usize`.
+///
+/// # Panics
+/// Panics if `s` is below 3.
+pub fn checked_centered_polygonal(s: usize, n: usize) -> Option<usize> {
+    check_order(s);
+    checked_polygonal(3, n)?.checked_mul(s)?.checked_add(1)
+}
+
+/// The n-th centered s-gonal number: `s × n(n + 1) / 2 + 1`.
+///
+/// # Panics
+/// Panics if `s` is below 3, or if the number doesn't fit in a `usize`.
+pub fn centered_polygonal(s: usize, n: usize) -> usize {
+    checked_centered_polygonal(s, n)
+        .expect("The centered polygonal number doesn't fit in a `usize`.")
+}
+
+/// Index of the given centered s-gonal number, if it is one.
+///
+/// # Panics
+/// Panics if `s` is below 3.
+pub fn which_centered_polygonal(s: usize, x: usize) -> Option<usize> {
+    check_order(s);
+    if x == 0 || !(x - 1).is_multiple_of(s) {
+        return None;
+    }
+    which_triangle((x - 1) / s)
+}
+
+/// Check if the given number is a centered s-gonal number.
+///
+/// # Panics
+/// Panics if `s` is below 3.
+pub fn is_centered_polygonal(s: usize, x: usize) -> bool {
+    which_centered_polygonal(s, x).is_some()
+}
+
+/// The n-th s-gonal pyramidal number, the sum of the first n s-gonal numbers:
+/// `n(n + 1)((s - 2)n - (s - 5)) / 6`, or `None` if it doesn't fit in a
+/// `usize`.
+///
+/// # Panics
+/// Panics if `s` is below 3.
+pub fn checked_pyramidal(s: usize, n: usize) -> Option<usize> {
+    check_order(s);
+    pyramidal_u128(s as u128, n as u128).and_then(|v| usize::try_from(v).ok())
+}
+
+/// The n-th s-gonal pyramidal number, the sum of the first n s-gonal numbers:
+/// `n(n + 1)((s - 2)n - (s - 5)) / 6`.
+///
+/// # Panics
+/// Panics if `s` is below 3, or if the number doesn't fit in a `usize`.
+pub fn pyramidal(s: usize, n: usize) -> usize {
+    checked_pyramidal(s, n).expect("The pyramidal number doesn't fit in a `usize`.")
+}
+
+/// Index of the given s-gonal pyramidal number, if it is one.
+///
+/// # Panics
+/// Panics if `s` is below 3.
+pub fn which_pyramidal(s: usize, x: usize) -> Option<usize> {
+    check_order(s);
+    // Pyramidal numbers grow like n³, so the index of any usize is below 2^23
+    search_index(x, 1 << 23, |n| pyramidal_u128(s as u128, n))
+}
+
+/// Check if the given number is an s-gonal pyramidal number.
+///
+/// # Panics
+/// Panics if `s` is below 3.
+pub fn is_pyramidal(s: usize, x: usize) -> bool {
+    which_pyramidal(s, x).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIMIT: usize = 10_000;
+
+    #[test]
+    fn test_polygonal_sequences() {
+        let first = |s| polygonals(s).take(6).collect::<Vec<usize>>();
+        assert_eq!(first(3), vec![1, 3, 6, 10, 15, 21]);
+        assert_eq!(first(4), vec![1, 4, 9, 16, 25, 36]);
+        assert_eq!(first(5), vec![1, 5, 12, 22, 35, 51]);
+        assert_eq!(first(6), vec![1, 6, 15, 28, 45, 66]);
+        assert_eq!(first(7), vec![1, 7, 18, 34, 55, 81]);
+        assert_eq!(first(8), vec![1, 8, 21, 40, 65, 96]);
+    }
+
+    #[test]
+    fn test_which_polygonal_matches_generator() {
+        for s in 3..=8 {
+            let numbers: Vec<usize> = polygonals(s).take_while(|&p| p <= LIMIT).collect();
+            for x in 1..=LIMIT {
+                let expected = numbers.iter().position(|&p| p == x).map(|i| i + 1);
+                assert_eq!(which_polygonal(s, x), expected, "{s} {x}");
+            }
+        }
+        for s in 3..=8 {
+            assert_eq!(which_polygonal(s, 0), Some(0));
+        }
+    }
+
+    #[test]
+    fn test_is_pentagonal() {
+        for x in [1, 5, 12, 22] {
+            assert!(is_pentagonal(x), "{x}");
+        }
+        for x in [2, 3, 7, 60] {
+            assert!(!is_pentagonal(x), "{x}");
+        }
+    }
+
+    #[test]
+    fn test_is_hexagonal() {
+        for x in [1, 6, 15, 28] {
+            assert!(is_hexagonal(x), "{x}");
+        }
+        for x in [2, 3, 7, 60] {
+            assert!(!is_hexagonal(x), "{x}");
+        }
+    }
+
+    #[test]
+    fn test_large_numbers() {
+        let n = 2_000_000_000;
+        assert_eq!(which_pentagonal(pentagonal(n)), Some(n));
+        assert_eq!(which_pentagonal(pentagonal(n) + 1), None);
+        assert_eq!(which_triangle(triangle(4_000_000_000)), Some(4_000_000_000));
+        assert_eq!(which_hexagonal(hexagonal(n) - 1), None);
+    }
+
+    #[test]
+    fn test_large_order() {
+        // polygonal(s, 2) = s and pyramidal(s, 2) = s + 1
+        let s = usize::MAX;
+        assert_eq!(which_polygonal(s, s), Some(2));
+        assert_eq!(which_polygonal(s, s - 1), None);
+        assert_eq!(which_polygonal(s, 1), Some(1));
+        assert_eq!(which_polygonal(1 << 40, 3 * (1 << 40) - 3), Some(3));
+        assert_eq!(which_pyramidal(s - 1, s), Some(2));
+        assert_eq!(which_pyramidal(s, s), None);
+        assert_eq!(which_pyramidal(s, 1), Some(1));
+        assert!(!is_polygonal(s, s - 2));
+        assert!(!is_pyramidal(s, s - 2));
+    }
+
+    #[test]
+    fn test_centered_polygonal() {
+        let first: Vec<usize> = (0..5).map(|n| centered_polygonal(6, n)).collect();
+        assert_eq!(first, vec![1, 7, 19, 37, 61]);
+        for s in 3..=8 {
+            for n in 0..50 {
+                let x = centered_polygonal(s, n);
+                assert_eq!(which_centered_polygonal(s, x), Some(n), "{s} {n}");
+                assert!(!is_centered_polygonal(s, x + 1), "{s} {n}");
+            }
+        }
+        assert!(!is_centered_polygonal(3, 0));
+    }
+
+    #[test]
+    fn test_pyramidal() {
+        let first: Vec<usize> = (0..6).map(|n| pyramidal(3, n)).collect();
+        assert_eq!(first, vec![0, 1, 4, 10, 20, 35]);
+        let first: Vec<usize> = (0..6).map(|n| pyramidal(4, n)).collect();
+        assert_eq!(first, vec![0, 1, 5, 14, 30, 55]);
+        for s in 3..=8 {
+            let sum: usize = polygonals(s).take(100).sum();
+            assert_eq!(pyramidal(s, 100), sum, "{s}");
+            for n in 1..100 {
+                let x = pyramidal(s, n);
+                assert_eq!(which_pyramidal(s, x), Some(n), "{s} {n}");
+                assert!(!is_pyramidal(s, x + 1), "{s} {n}");
+            }
+        }
+        assert_eq!(which_pyramidal(4, 0), Some(0));
+        assert_eq!(which_pyramidal(4, usize::MAX), None);
+    }
+
+    #[test]
+    fn test_checked_generators() {
+        assert_eq!(checked_polygonal(3, 1 << 33), None);
+        assert_eq!(checked_polygonal(3, usize::MAX), None);
+        assert_eq!(checked_polygonal(usize::MAX, 2), Some(usize::MAX));
+        assert_eq!(checked_polygonal(usize::MAX, 3), None);
+        assert_eq!(checked_polygonal(6, 1 << 31), Some(hexagonal(1 << 31)));
+        assert_eq!(checked_centered_polygonal(3, 1 << 32), None);
+        assert_eq!(checked_centered_polygonal(usize::MAX, 1), None);
+        assert_eq!(checked_centered_polygonal(6, 3), Some(37));
+        assert_eq!(checked_pyramidal(3, 1 << 23), None);
+        assert_eq!(checked_pyramidal(usize::MAX, usize::MAX), None);
+        assert_eq!(checked_pyramidal(4, 100), Some(pyramidal(4, 100)));
+        assert_eq!(polygonals(usize::MAX).count(), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_polygonal_overflow() {
+        polygonal(3, 1 << 33);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_digon() {
+        polygonal(2, 10);
+    }
+}
@@ -0,0 +1,93 @@
+use super::{mod_mul, mod_pow};
+
+/// Bases for which the Miller–Rabin test is deterministic on the whole `u64`
+/// range (the first 12 primes are enough below 3.18 × 10^23).
+const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// Check if the given number is prime, with the Miller–Rabin test.
+///
+/// The test is deterministic: there is no false positive on the whole `u64`
+/// range. It takes `O(log(n))` modular multiplications, so it's much faster
+/// than trial division for large numbers.
+///
+/// # Examples
+/// ```
+/// use reuler::utils::miller_rabin;
+///
+/// assert!(miller_rabin(1_000_000_007));
+/// assert!(miller_rabin(18_446_744_073_709_551_557));
+/// assert!(!miller_rabin(561));
+/// assert!(!miller_rabin(u64::MAX));
+/// ```
+pub fn miller_rabin(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    for p in BASES {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+
+    // n - 1 = d × 2^s, with d odd
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+
+    BASES.iter().all(|&a| {
+        let mut x = mod_pow(a, d, n);
+        if x == 1 || x == n - 1 {
+            return true;
+        }
+        for _ in 1..s {
+            x = mod_mul(x, x, n);
+            if x == n - 1 {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::Sieve;
+
+    #[test]
+    fn test_matches_sieve() {
+        let sieve = Sieve::new(100_000);
+        for n in 0..=100_000 {
+            assert_eq!(miller_rabin(n as u64), sieve.is_prime(n), "{n}");
+        }
+    }
+
+    #[test]
+    fn test_large_primes() {
+        for p in [
+            2_147_483_647,
+            1_000_000_000_039,
+            2_305_843_009_213_693_951,
+            18_446_744_073_709_551_557,
+        ] {
+            assert!(miller_rabin(p), "{p}");
+        }
+    }
+
+    #[test]
+    fn test_pseudoprimes() {
+        for n in [
+            // Carmichael numbers
+            561,
+            41_041,
+            // Strong pseudoprime to the bases 2, 3, 5 and 7
+            3_215_031_751,
+            // Strong pseudoprime to the prime bases up to 23
+            3_825_123_056_546_413_051,
+            // Product of the two largest primes below 2^32
+            4_294_967_291 * 4_294_967_279,
+            u64::MAX,
+        ] {
+            assert!(!miller_rabin(n), "{n}");
+        }
+    }
+}
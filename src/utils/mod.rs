@@ -1,5 +1,29 @@
+mod bigint;
+mod factor;
+pub mod figurate;
+mod miller_rabin;
+mod modular;
+mod multiplicative;
+mod prime_count;
+mod roots;
+mod segmented;
+mod sieve;
+mod signed_bigint;
+
+pub use bigint::BigInt;
+pub use factor::factor;
+pub use miller_rabin::miller_rabin;
+pub use modular::{crt, ext_gcd, lcm, mod_inv, mod_mul, mod_pow, Unsigned};
+pub use multiplicative::{
+    mu, mu_sieve, phi, phi_sieve, radical, radical_sieve, sigma, sigma_sieve, tau, tau_sieve,
+};
+pub use prime_count::{prime_count, prime_sum};
+pub use roots::{icbrt, iroot, is_perfect_power, is_square, isqrt};
+pub use segmented::SegmentedPrimes;
+pub use sieve::{Sieve, SievePrimes};
+pub use signed_bigint::SignedBigInt;
+
 use std::collections::HashSet;
-use std::ops;
 
 /// Iteratively compute the Fibonacci sequence.
 ///
@@ -70,6 +94,11 @@ impl Iterator for Fibonacci {
 /// }
 /// assert_eq!(last_p, 19);
 /// ```
+///
+/// # Notes
+/// When the limit is known, `Sieve` is much faster.
+/// Every prime found is kept in memory: to get the primes in a high window,
+/// use `SegmentedPrimes` instead.
 pub struct Primes {
     p: usize,
     previous_primes: Vec<usize>,
@@ -136,7 +165,7 @@ impl Iterator for Primes {
 
                 // When we have a limit, we don't need to store past primes that
                 // are over the square root of the limit, it will be faster
-                if self.p as f64 <= (lim as f64).sqrt() {
+                if self.p <= isqrt(lim) {
                     self.previous_primes.push(self.p);
                 }
                 Some(self.p)
@@ -164,20 +193,23 @@ impl Iterator for Primes {
 /// ```
 pub fn get_divisors(x: usize) -> HashSet<usize> {
     let mut divisors = HashSet::new();
-    let mut i = 1;
 
-    while i as f64 <= (x as f64).sqrt() {
-        if x % i == 0 {
+    for i in 1..=isqrt(x) {
+        if x.is_multiple_of(i) {
             divisors.insert(i);
             divisors.insert(x / i);
         }
-
-        i += 1;
     }
     divisors
 }
 
-/// Compute the list of divisors for the given number.
+/// Below this, `is_prime` uses trial division rather than Miller–Rabin.
+const MILLER_RABIN_THRESHOLD: usize = 1 << 12;
+
+/// Check if the given number is prime.
+///
+/// Small numbers are checked by trial division, larger ones with the
+/// deterministic Miller–Rabin test (see `miller_rabin`).
 ///
 /// # Examples
 /// ```
@@ -190,15 +222,16 @@ pub fn is_prime(x: usize) -> bool {
     match x {
         1 => false,
         2 => true,
-        _ if x % 2 == 0 => false,
-        _ => {
-            let divisors = get_divisors(x);
-            divisors.len() == 2
-        }
+        _ if x.is_multiple_of(2) => false,
+        _ if x < MILLER_RABIN_THRESHOLD => (3..)
+            .step_by(2)
+            .take_while(|d| d * d <= x)
+            .all(|d| !x.is_multiple_of(d)),
+        _ => miller_rabin(x as u64),
     }
 }
 
-/// Compute the list of divisors for the given number.
+/// Check if the absolute value of the given number is prime.
 ///
 /// # Examples
 /// ```
@@ -209,8 +242,7 @@ pub fn is_prime(x: usize) -> bool {
 /// assert!(!reuler::utils::is_prime_with_neg(8));
 /// ```
 pub fn is_prime_with_neg(x: isize) -> bool {
-    let x = if x < 0 { -x } else { x };
-    is_prime(usize::try_from(x).unwrap())
+    is_prime(x.unsigned_abs())
 }
 
 /// Compute the list of **proper** divisors for the given number.
@@ -326,128 +358,6 @@ pub fn digits_of(x: usize) -> Vec<usize> {
     digits_of_base(x, 10)
 }
 
-/// Structure used to deal with arbtrarily large numbers.
-///
-/// # Note
-/// For now, only addition between two BigInt, and multiplication-assign with
-/// a `usize` is supported.
-///
-/// # Examples
-/// ```
-/// let mut x = reuler::utils::BigInt::from(128);
-/// let y = reuler::utils::BigInt::new();  // It's 0
-/// x = &x + &y;
-/// x *= 2;
-///
-/// assert_eq!(x.to_string(), String::from("256"));
-/// ```
-#[derive(Debug, PartialEq)]
-pub struct BigInt {
-    pub digits: Vec<usize>,
-}
-
-impl BigInt {
-    /// Create a new BigInt, starting at 0. To create a BigInt from an existing
-    /// number, use `BigInt::from()` instead.
-    pub fn new() -> Self {
-        Self { digits: vec![0] }
-    }
-
-    /// Create a new BigInt from the given number.
-    pub fn from(x: usize) -> Self {
-        Self {
-            digits: digits_of(x),
-        }
-    }
-
-    /// Function to get the number as a string.
-    pub fn to_string(&self) -> String {
-        let mut s = String::new();
-        for d in self.digits.iter().rev() {
-            s.push_str(&d.to_string());
-        }
-        s
-    }
-
-    /// Create a copy of the current number, with the digits reversed.
-    pub fn reverse(&self) -> Self {
-        Self {
-            digits: self.digits.clone().into_iter().rev().collect(),
-        }
-    }
-
-    /// Function to check if the current number is a palindrome.
-    pub fn is_palindrome(&self) -> bool {
-        is_palindrome(&self.digits)
-    }
-}
-
-impl ops::Add<&BigInt> for &BigInt {
-    type Output = BigInt;
-
-    /// Overload the addition for BigInt.
-    fn add(self, x: &BigInt) -> Self::Output {
-        // Clone the digits of self, we will update them and create a new BigInt
-        let mut digits = self.digits.clone();
-
-        // Make both digits the same length
-        while digits.len() < x.digits.len() {
-            digits.push(0);
-        }
-
-        // Add the digits together, one-by-one
-        let mut carry_over = 0;
-        for i in 0..digits.len() {
-            let x_digit = if i < x.digits.len() { x.digits[i] } else { 0 };
-            let digit_result = digits[i] + x_digit + carry_over;
-
-            // Update the current digit
-            digits[i] = digit_result % 10;
-
-            // Update carry over
-            carry_over = digit_result / 10;
-        }
-
-        while carry_over != 0 {
-            // We have a carry over left, add a new digit
-            digits.push(carry_over % 10);
-
-            carry_over = carry_over / 10;
-        }
-
-        BigInt { digits: digits }
-    }
-}
-
-impl ops::MulAssign<usize> for BigInt {
-    /// Overload the multiplication-assign for BigInt.
-    fn mul_assign(&mut self, x: usize) {
-        // Multiply each digit, one-by-one
-        let mut carry_over = 0;
-        for i in 0..self.digits.len() {
-            let digit_result = self.digits[i] * x + carry_over;
-
-            // Update the current digit
-            self.digits[i] = digit_result % 10;
-
-            // Update carry over
-            carry_over = digit_result / 10;
-        }
-
-        while carry_over != 0 {
-            // We have a carry over left, add a new digit
-            self.digits.push(carry_over % 10);
-
-            carry_over = carry_over / 10;
-        }
-
-        // If we multiply by 0, we have to remove some digits to have only a single 0
-        while self.digits.len() > 1 && self.digits[self.digits.len() - 1] == 0 {
-            self.digits.pop();
-        }
-    }
-}
-
 /// Function that check if an iterable is a palindrome or not.
 ///
 /// # Examples
@@ -539,53 +449,6 @@ pub fn digits_to_number(digits: Vec<usize>) -> usize {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_bigint_addition_same_number_of_digits() {
-        assert_eq!(
-            &BigInt::from(125) + &BigInt::from(988),
-            BigInt::from(125 + 988)
-        );
-    }
-
-    #[test]
-    fn test_bigint_addition_more_digits() {
-        assert_eq!(&BigInt::from(125) + &BigInt::from(7), BigInt::from(125 + 7));
-    }
-
-    #[test]
-    fn test_bigint_addition_less_digits() {
-        assert_eq!(
-            &BigInt::from(56) + &BigInt::from(4852),
-            BigInt::from(56 + 4852)
-        );
-    }
-
-    #[test]
-    fn test_bigint_new() {
-        assert_eq!(BigInt::new(), BigInt::from(0));
-    }
-
-    #[test]
-    fn test_bigint_multiplication_1() {
-        let mut big_int = BigInt::from(75);
-        big_int *= 3;
-        assert_eq!(big_int, BigInt::from(75 * 3));
-    }
-
-    #[test]
-    fn test_bigint_multiplication_2() {
-        let mut big_int = BigInt::from(3);
-        big_int *= 75;
-        assert_eq!(big_int, BigInt::from(3 * 75));
-    }
-
-    #[test]
-    fn test_bigint_multiplication_by_zero() {
-        let mut big_int = BigInt::from(9888);
-        big_int *= 0;
-        assert_eq!(big_int, BigInt::new());
-    }
-
     #[test]
     fn test_digit_of_0() {
         let digits = digits_of(0);
@@ -608,4 +471,15 @@ mod tests {
     fn test_empty_digits() {
         assert_eq!(digits_to_number(Vec::new()), 0);
     }
+
+    #[test]
+    fn test_is_prime_around_threshold() {
+        let sieve = Sieve::new(2 * MILLER_RABIN_THRESHOLD);
+        for x in 0..=2 * MILLER_RABIN_THRESHOLD {
+            assert_eq!(is_prime(x), sieve.is_prime(x), "{x}");
+        }
+        assert!(is_prime(999_999_999_989));
+        assert!(!is_prime_with_neg(-999_999_999_987));
+        assert!(!is_prime_with_neg(isize::MIN));
+    }
 }
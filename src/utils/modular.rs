@@ -0,0 +1,355 @@
+use std::fmt::Debug;
+use std::ops::{Add, BitAnd, Div, Mul, Rem, Shr, Sub};
+
+/// Unsigned integer types supported by the modular arithmetic helpers
+/// (`u32`, `u64`, `u128` and `usize`).
+pub trait Unsigned:
+    Copy
+    + Ord
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + BitAnd<Output = Self>
+    + Shr<u32, Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// Multiply, returning `None` on overflow.
+    fn checked_mul(self, other: Self) -> Option<Self>;
+
+    /// Compute `self × other mod m` without overflow.
+    fn mul_mod(self, other: Self, m: Self) -> Self;
+
+    /// Convert to `i128`, for the Bézout coefficients.
+    fn to_i128(self) -> i128;
+}
+
+/// Implement `Unsigned` for a type, multiplying in a wider type.
+macro_rules! unsigned_widening {
+    ($t:ty, $wide:ty) => {
+        impl Unsigned for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                <$t>::checked_mul(self, other)
+            }
+
+            fn mul_mod(self, other: Self, m: Self) -> Self {
+                (self as $wide * other as $wide % m as $wide) as $t
+            }
+
+            fn to_i128(self) -> i128 {
+                self as i128
+            }
+        }
+    };
+}
+
+unsigned_widening!(u32, u64);
+unsigned_widening!(u64, u128);
+unsigned_widening!(usize, u128);
+
+impl Unsigned for u128 {
+    const ZERO: Self = 0;
+    const ONE: Self = 1;
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        u128::checked_mul(self, other)
+    }
+
+    fn mul_mod(self, other: Self, m: Self) -> Self {
+        if let Some(product) = self.checked_mul(other) {
+            return product % m;
+        }
+
+        // No wider type : double and add
+        let (mut a, mut b) = (self % m, other % m);
+        let mut result = 0;
+        while b > 0 {
+            if b & 1 == 1 {
+                result = add_mod(result, a, m);
+            }
+            a = add_mod(a, a, m);
+            b >>= 1;
+        }
+        result
+    }
+
+    fn to_i128(self) -> i128 {
+        i128::try_from(self).expect("The number doesn't fit in an i128.")
+    }
+}
+
+/// Compute `a + b mod m` without overflow, for `a, b < m`.
+fn add_mod<T: Unsigned>(a: T, b: T, m: T) -> T {
+    if a >= m - b {
+        a - (m - b)
+    } else {
+        a + b
+    }
+}
+
+/// Compute `a - b mod m`, for `a, b < m`.
+fn sub_mod<T: Unsigned>(a: T, b: T, m: T) -> T {
+    if a >= b {
+        a - b
+    } else {
+        m - (b - a)
+    }
+}
+
+/// Greatest common divisor.
+fn gcd<T: Unsigned>(mut a: T, mut b: T) -> T {
+    while b > T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Compute `a × b mod m`, without overflow even when `a × b` doesn't fit in
+/// the type.
+///
+/// # Panics
+/// Panics if `m` is 0.
+///
+/// # Examples
+/// ```
+/// use reuler::utils::mod_mul;
+///
+/// assert_eq!(mod_mul(7u32, 8, 10), 6);
+/// assert_eq!(mod_mul(u64::MAX - 1, u64::MAX - 1, u64::MAX), 1);
+/// assert_eq!(mod_mul(u128::MAX - 1, 2, u128::MAX), u128::MAX - 2);
+/// ```
+pub fn mod_mul<T: Unsigned>(a: T, b: T, m: T) -> T {
+    a.mul_mod(b, m)
+}
+
+/// Compute `base^exp mod m`, using exponentiation by squaring.
+///
+/// # Panics
+/// Panics if `m` is 0.
+///
+/// # Examples
+/// ```
+/// use reuler::utils::mod_pow;
+///
+/// assert_eq!(mod_pow(3u32, 3, 10), 7);
+/// assert_eq!(mod_pow(25u64, 25, 10_000_000_000), 3447265625);
+/// assert_eq!(mod_pow(5u128, 0, 1), 0);
+/// ```
+pub fn mod_pow<T: Unsigned>(base: T, exp: T, m: T) -> T {
+    let mut result = T::ONE % m;
+    let mut base = base % m;
+    let mut exp = exp;
+    while exp > T::ZERO {
+        if exp & T::ONE == T::ONE {
+            result = mod_mul(result, base, m);
+        }
+        base = mod_mul(base, base, m);
+        exp = exp >> 1;
+    }
+    result
+}
+
+/// Extended Euclidean algorithm: compute `(g, x, y)` such that `g` is the
+/// greatest common divisor of `a` and `b`, and `a × x + b × y = g`.
+///
+/// # Panics
+/// Panics if `a` or `b` doesn't fit in an `i128`.
+///
+/// # Examples
+/// ```
+/// let (g, x, y) = reuler::utils::ext_gcd(240u32, 46);
+///
+/// assert_eq!(g, 2);
+/// assert_eq!(240 * x + 46 * y, 2);
+/// ```
+pub fn ext_gcd<T: Unsigned>(a: T, b: T) -> (T, i128, i128) {
+    let (mut r0, mut r1) = (a.to_i128(), b.to_i128());
+    let (mut x0, mut x1) = (1, 0);
+    let (mut y0, mut y1) = (0, 1);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+    (gcd(a, b), x0, y0)
+}
+
+/// Modular inverse of `a` modulo `m`, if `a` and `m` are coprime.
+///
+/// # Panics
+/// Panics if `m` is 0.
+///
+/// # Examples
+/// ```
+/// use reuler::utils::mod_inv;
+///
+/// assert_eq!(mod_inv(3u64, 7), Some(5));
+/// assert_eq!(mod_inv(4u64, 8), None);
+/// ```
+pub fn mod_inv<T: Unsigned>(a: T, m: T) -> Option<T> {
+    // Euclid's algorithm, keeping the coefficient of a modulo m
+    let (mut r0, mut r1) = (m, a % m);
+    let (mut t0, mut t1) = (T::ZERO, T::ONE % m);
+    while r1 > T::ZERO {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (t0, t1) = (t1, sub_mod(t0, mod_mul(q % m, t1, m), m));
+    }
+    (r0 == T::ONE).then_some(t0)
+}
+
+/// Least common multiple, or `None` if it doesn't fit in the type. The least
+/// common multiple with 0 is 0.
+///
+/// # Examples
+/// ```
+/// assert_eq!(reuler::utils::lcm(4u32, 6), Some(12));
+/// assert_eq!(reuler::utils::lcm(0u32, 6), Some(0));
+/// assert_eq!(reuler::utils::lcm(u32::MAX, 2), None);
+/// ```
+pub fn lcm<T: Unsigned>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// Chinese remainder theorem: find `x` such that `x ≡ r (mod m)` for every
+/// `(r, m)` of the given congruences. The moduli don't need to be coprime.
+///
+/// The solution is returned as `(x, l)` where `l` is the least common
+/// multiple of the moduli and `x < l` : every `x + k × l` is a solution too.
+/// `None` is returned if the congruences are incompatible, or if `l` doesn't
+/// fit in the type.
+///
+/// # Panics
+/// Panics if one of the moduli is 0.
+///
+/// # Examples
+/// ```
+/// use reuler::utils::crt;
+///
+/// assert_eq!(crt(&[(2u64, 3), (3, 5), (2, 7)]), Some((23, 105)));
+/// assert_eq!(crt(&[(2u64, 4), (4, 6)]), Some((10, 12)));
+/// assert_eq!(crt(&[(1u64, 4), (2, 6)]), None);
+/// ```
+pub fn crt<T: Unsigned>(congruences: &[(T, T)]) -> Option<(T, T)> {
+    let (mut x, mut l) = (T::ZERO, T::ONE);
+    for &(r, m) in congruences {
+        assert!(m > T::ZERO, "The moduli can't be 0.");
+
+        // x + l × k ≡ r (mod m), solved for k modulo m / g
+        let g = gcd(l, m);
+        let diff = sub_mod(r % m, x % m, m);
+        if diff % g != T::ZERO {
+            return None;
+        }
+        let m_g = m / g;
+        let k = mod_mul(diff / g, mod_inv(l / g, m_g)?, m_g);
+
+        // x < l and k < m / g, so x + l × k < l × m / g fits when the new
+        // modulus does
+        let new_l = l.checked_mul(m_g)?;
+        x = x + l * k;
+        l = new_l;
+    }
+    Some((x, l))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mod_mul_matches_u128() {
+        let values = [0, 1, 2, 3, 1 << 31, u32::MAX - 1, u32::MAX];
+        for &a in &values {
+            for &b in &values {
+                for &m in &values[1..] {
+                    let expected = (a as u128 * b as u128 % m as u128) as u32;
+                    assert_eq!(mod_mul(a, b, m), expected, "{a} {b} {m}");
+                    assert_eq!(mod_mul(a as u128, b as u128, m as u128), expected as u128);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_mod_mul_u128_overflow() {
+        // (2^127) × 2 ≡ 1 (mod 2^128 - 1)
+        assert_eq!(mod_mul(1u128 << 127, 2, u128::MAX), 1);
+        let m = u128::MAX - 158; // Largest prime below 2^128
+        assert_eq!(mod_mul(m - 1, m - 1, m), 1);
+    }
+
+    #[test]
+    fn test_mod_pow() {
+        assert_eq!(mod_pow(364u32, 0, 10), 1);
+        assert_eq!(mod_pow(3u32, 3, 1000), 27);
+        assert_eq!(mod_pow(4u64, 4, 10000), 4 * 4 * 4 * 4);
+        assert_eq!(mod_pow(25usize, 25, 10_000_000_000), 3447265625);
+        // Fermat's little theorem
+        let p = u64::MAX - 58; // Largest prime below 2^64
+        assert_eq!(mod_pow(123_456_789u64, p - 1, p), 1);
+        let p = u128::MAX - 158;
+        assert_eq!(mod_pow(2u128, p - 1, p), 1);
+    }
+
+    #[test]
+    fn test_ext_gcd() {
+        for a in 0..50u32 {
+            for b in 0..50u32 {
+                let (g, x, y) = ext_gcd(a, b);
+                assert_eq!(g, gcd(a, b));
+                assert_eq!(a as i128 * x + b as i128 * y, g as i128, "{a} {b}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_mod_inv() {
+        for m in 1..100u64 {
+            for a in 0..m {
+                match mod_inv(a, m) {
+                    Some(inv) => assert_eq!(mod_mul(a, inv, m), 1 % m, "{a} {m}"),
+                    None => assert_ne!(gcd(a, m), 1, "{a} {m}"),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_crt_matches_brute_force() {
+        for m1 in 1..12u32 {
+            for m2 in 1..12u32 {
+                for r1 in 0..m1 {
+                    for r2 in 0..m2 {
+                        let l = lcm(m1, m2).unwrap();
+                        let expected = (0..l).find(|x| x % m1 == r1 && x % m2 == r2);
+                        assert_eq!(crt(&[(r1, m1), (r2, m2)]), expected.map(|x| (x, l)));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_crt_overflow() {
+        assert_eq!(crt(&[(0u32, 1 << 20), (0, 3 << 15)]), Some((0, 3 << 20)));
+        assert_eq!(crt(&[(0u32, 1 << 20), (0, 3 << 20)]), Some((0, 3 << 20)));
+        assert_eq!(crt(&[(0u32, 65_537), (0, 65_539)]), None);
+        assert_eq!(
+            crt(&[(5u64, 4_294_967_291), (7, 4_294_967_279), (3, 65537)]),
+            None
+        );
+        assert_eq!(crt::<u32>(&[]), Some((0, 1)));
+    }
+}
@@ -0,0 +1,325 @@
+use super::factor;
+use std::ops::Mul;
+
+/// A `usize` which becomes `None` once a product overflows.
+#[derive(Clone, Copy)]
+struct Checked(Option<usize>);
+
+impl Mul for Checked {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Checked(self.0.zip(other.0).and_then(|(a, b)| a.checked_mul(b)))
+    }
+}
+
+/// Compute a multiplicative function from the prime factorization of `n`,
+/// given its value `g(p, e)` on the prime powers `p^e`. The value at 0 is
+/// `zero`.
+fn multiplicative<T>(n: usize, zero: T, one: T, g: impl Fn(usize, u32) -> T) -> T
+where
+    T: Mul<Output = T>,
+{
+    if n == 0 {
+        return zero;
+    }
+    factor(n).into_iter().fold(one, |acc, (p, e)| acc * g(p, e))
+}
+
+/// Fill a table of a multiplicative function for every number up to `limit`
+/// (included), given its value `g(p, e)` on the prime powers `p^e`.
+///
+/// The smallest prime factor of every number is found with a linear sieve, so
+/// each value is computed from a smaller one with a single call to `g`. The
+/// value at index 0 is `zero`.
+fn multiplicative_sieve<T>(limit: usize, zero: T, one: T, g: impl Fn(usize, u32) -> T) -> Vec<T>
+where
+    T: Mul<Output = T> + Copy,
+{
+    // For each n : its smallest prime factor p, the exponent e of p in n, and
+    // p^e
+    let mut smallest = vec![0; limit + 1];
+    let mut exponent = vec![0; limit + 1];
+    let mut power = vec![0; limit + 1];
+    let mut primes = Vec::new();
+
+    let mut table = vec![zero; limit + 1];
+    if limit >= 1 {
+        table[1] = one;
+    }
+
+    for n in 2..=limit {
+        if smallest[n] == 0 {
+            smallest[n] = n;
+            exponent[n] = 1;
+            power[n] = n;
+            primes.push(n);
+        }
+
+        // Each composite is crossed out once, by its smallest prime factor
+        for &p in &primes {
+            if p > smallest[n] || n * p > limit {
+                break;
+            }
+            let m = n * p;
+            smallest[m] = p;
+            if p == smallest[n] {
+                exponent[m] = exponent[n] + 1;
+                power[m] = power[n] * p;
+            } else {
+                exponent[m] = 1;
+                power[m] = p;
+            }
+        }
+
+        table[n] = table[n / power[n]] * g(smallest[n], exponent[n]);
+    }
+    table
+}
+
+/// Euler's totient function: how many numbers up to `n` are coprime with
+/// `n`, with `phi(0) = 0`.
+///
+/// # Examples
+/// ```
+/// assert_eq!(reuler::utils::phi(1), 1);
+/// assert_eq!(reuler::utils::phi(9), 6);
+/// assert_eq!(reuler::utils::phi(36), 12);
+/// ```
+pub fn phi(n: usize) -> usize {
+    multiplicative(n, 0, 1, phi_of_prime_power)
+}
+
+/// Number of divisors of `n`, with `tau(0) = 0`.
+///
+/// # Examples
+/// ```
+/// assert_eq!(reuler::utils::tau(1), 1);
+/// assert_eq!(reuler::utils::tau(28), 6);
+/// ```
+pub fn tau(n: usize) -> usize {
+    multiplicative(n, 0, 1, |_, e| e as usize + 1)
+}
+
+/// Sum of the k-th powers of the divisors of `n`. `sigma(n, 0)` is the number
+/// of divisors and `sigma(n, 1)` their sum, with `sigma(0, k) = 0`.
+///
+/// # Panics
+/// Panics if the sum doesn't fit in a `usize`.
+///
+/// # Examples
+/// ```
+/// assert_eq!(reuler::utils::sigma(28, 0), 6);
+/// assert_eq!(reuler::utils::sigma(28, 1), 56);
+/// assert_eq!(reuler::utils::sigma(6, 2), 50);
+/// ```
+pub fn sigma(n: usize, k: u32) -> usize {
+    multiplicative(n, Checked(Some(0)), Checked(Some(1)), |p, e| {
+        Checked(sigma_of_prime_power(p, e, k))
+    })
+    .0
+    .expect("The sum of the powers of the divisors doesn't fit in a `usize`.")
+}
+
+/// Möbius function: 0 if `n` has a squared prime factor, otherwise 1 or -1
+/// whether `n` has an even or odd number of prime factors, with `mu(0) = 0`.
+///
+/// # Examples
+/// ```
+/// assert_eq!(reuler::utils::mu(1), 1);
+/// assert_eq!(reuler::utils::mu(30), -1);
+/// assert_eq!(reuler::utils::mu(12), 0);
+/// ```
+pub fn mu(n: usize) -> i8 {
+    multiplicative(n, 0, 1, mu_of_prime_power)
+}
+
+/// Radical of `n`: the product of its distinct prime factors, with
+/// `radical(0) = 0`.
+///
+/// # Examples
+/// ```
+/// assert_eq!(reuler::utils::radical(1), 1);
+/// assert_eq!(reuler::utils::radical(504), 42);
+/// ```
+pub fn radical(n: usize) -> usize {
+    multiplicative(n, 0, 1, |p, _| p)
+}
+
+/// Table of `phi(n)` for every `n` up to `limit` (included), with
+/// `phi(0) = 0`.
+///
+/// # Examples
+/// ```
+/// assert_eq!(reuler::utils::phi_sieve(6), vec![0, 1, 1, 2, 2, 4, 2]);
+/// ```
+pub fn phi_sieve(limit: usize) -> Vec<usize> {
+    multiplicative_sieve(limit, 0, 1, phi_of_prime_power)
+}
+
+/// Table of `tau(n)` for every `n` up to `limit` (included), with
+/// `tau(0) = 0`.
+///
+/// # Examples
+/// ```
+/// assert_eq!(reuler::utils::tau_sieve(6), vec![0, 1, 2, 2, 3, 2, 4]);
+/// ```
+pub fn tau_sieve(limit: usize) -> Vec<usize> {
+    multiplicative_sieve(limit, 0, 1, |_, e| e as usize + 1)
+}
+
+/// Table of `sigma(n, k)` for every `n` up to `limit` (included), with
+/// `sigma(0, k) = 0`.
+///
+/// # Panics
+/// Panics if one of the sums doesn't fit in a `usize`.
+///
+/// # Examples
+/// ```
+/// assert_eq!(reuler::utils::sigma_sieve(6, 1), vec![0, 1, 3, 4, 7, 6, 12]);
+/// ```
+pub fn sigma_sieve(limit: usize, k: u32) -> Vec<usize> {
+    let table = multiplicative_sieve(limit, Checked(Some(0)), Checked(Some(1)), |p, e| {
+        Checked(sigma_of_prime_power(p, e, k))
+    });
+    table
+        .into_iter()
+        .map(|value| {
+            value
+                .0
+                .expect("The sum of the powers of the divisors doesn't fit in a `usize`.")
+        })
+        .collect()
+}
+
+/// Table of `mu(n)` for every `n` up to `limit` (included), with `mu(0) = 0`.
+///
+/// # Examples
+/// ```
+/// assert_eq!(reuler::utils::mu_sieve(6), vec![0, 1, -1, -1, 0, -1, 1]);
+/// ```
+pub fn mu_sieve(limit: usize) -> Vec<i8> {
+    multiplicative_sieve(limit, 0, 1, mu_of_prime_power)
+}
+
+/// Table of `radical(n)` for every `n` up to `limit` (included), with
+/// `radical(0) = 0`.
+///
+/// # Examples
+/// ```
+/// assert_eq!(reuler::utils::radical_sieve(6), vec![0, 1, 2, 3, 2, 5, 6]);
+/// ```
+pub fn radical_sieve(limit: usize) -> Vec<usize> {
+    multiplicative_sieve(limit, 0, 1, |p, _| p)
+}
+
+fn phi_of_prime_power(p: usize, e: u32) -> usize {
+    p.pow(e - 1) * (p - 1)
+}
+
+/// `1 + p^k + p^2k + ... + p^ek`, or `None` if it doesn't fit in a `usize`.
+fn sigma_of_prime_power(p: usize, e: u32, k: u32) -> Option<usize> {
+    let pk = p.checked_pow(k)?;
+    let mut term: usize = 1;
+    let mut sum: usize = 1;
+    for _ in 0..e {
+        term = term.checked_mul(pk)?;
+        sum = sum.checked_add(term)?;
+    }
+    Some(sum)
+}
+
+fn mu_of_prime_power(_: usize, e: u32) -> i8 {
+    if e == 1 {
+        -1
+    } else {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils;
+
+    const LIMIT: usize = 2000;
+
+    #[test]
+    fn test_matches_divisors() {
+        for n in 1..=LIMIT {
+            let divisors = utils::get_divisors(n);
+            assert_eq!(tau(n), divisors.len(), "{n}");
+            assert_eq!(sigma(n, 1), divisors.iter().sum::<usize>(), "{n}");
+            assert_eq!(
+                sigma(n, 2),
+                divisors.iter().map(|d| d * d).sum::<usize>(),
+                "{n}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_phi_matches_gcf() {
+        for n in 1..=LIMIT {
+            let coprimes = (1..=n).filter(|&k| utils::gcf(n, k) == 1).count();
+            assert_eq!(phi(n), coprimes, "{n}");
+        }
+    }
+
+    #[test]
+    fn test_sieves_match_functions() {
+        let phis = phi_sieve(LIMIT);
+        let taus = tau_sieve(LIMIT);
+        let sigmas = sigma_sieve(LIMIT, 1);
+        let mus = mu_sieve(LIMIT);
+        let radicals = radical_sieve(LIMIT);
+        for n in 1..=LIMIT {
+            assert_eq!(phis[n], phi(n), "{n}");
+            assert_eq!(taus[n], tau(n), "{n}");
+            assert_eq!(sigmas[n], sigma(n, 1), "{n}");
+            assert_eq!(mus[n], mu(n), "{n}");
+            assert_eq!(radicals[n], radical(n), "{n}");
+        }
+    }
+
+    #[test]
+    fn test_mertens() {
+        // Sum of mu(n) for n up to 1000
+        assert_eq!(mu_sieve(1000).iter().map(|&m| m as isize).sum::<isize>(), 2);
+    }
+
+    #[test]
+    fn test_zero() {
+        assert_eq!(phi(0), 0);
+        assert_eq!(tau(0), 0);
+        assert_eq!(sigma(0, 1), 0);
+        assert_eq!(mu(0), 0);
+        assert_eq!(radical(0), 0);
+    }
+
+    #[test]
+    fn test_large_sigma() {
+        // 2^63 + 2^62 + ... + 1 = 2^64 - 1
+        assert_eq!(sigma(1 << 63, 1), usize::MAX);
+        assert_eq!(sigma(3, 40), 3_usize.pow(40) + 1);
+        assert_eq!(sigma_sieve(3, 40)[3], 3_usize.pow(40) + 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sigma_overflow() {
+        sigma(3, 41);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sigma_sieve_overflow() {
+        sigma_sieve(6, 25);
+    }
+
+    #[test]
+    fn test_small_limits() {
+        assert_eq!(phi_sieve(0), vec![0]);
+        assert_eq!(mu_sieve(1), vec![0, 1]);
+    }
+}
@@ -0,0 +1,123 @@
+use super::isqrt;
+use std::ops::{Mul, Sub};
+
+/// Count the primes up to `x` (included), without enumerating them.
+///
+/// This is Lucy_Hedgehog's algorithm, in `O(x^(3/4))` time and `O(sqrt(x))`
+/// memory: π(10^11) takes well under a second.
+///
+/// # Examples
+/// ```
+/// use reuler::utils::prime_count;
+///
+/// assert_eq!(prime_count(10), 4);
+/// assert_eq!(prime_count(1_000_000), 78498);
+/// assert_eq!(prime_count(10_000_000_000), 455052511);
+/// ```
+pub fn prime_count(x: usize) -> usize {
+    lucy(x, |v| v.saturating_sub(1), |_| 1)
+}
+
+/// Sum the primes up to `x` (included), without enumerating them.
+///
+/// Same algorithm as `prime_count`. The sum is always below `x²`, so it can't
+/// overflow.
+///
+/// # Examples
+/// ```
+/// use reuler::utils::prime_sum;
+///
+/// assert_eq!(prime_sum(10), 17);
+/// assert_eq!(prime_sum(2_000_000), 142913828922);
+/// ```
+pub fn prime_sum(x: usize) -> u128 {
+    lucy(
+        x,
+        |v| {
+            let v = v as u128;
+            (v * (v + 1) / 2).saturating_sub(1)
+        },
+        |p| p as u128,
+    )
+}
+
+/// Lucy_Hedgehog's algorithm for `F(x)`, the sum of `f(p)` for every prime
+/// `p <= x`, where `f` is completely multiplicative.
+///
+/// `sum(v)` is the sum of `f(n)` for `2 <= n <= v`, and `f(p)` is the value
+/// for a single prime. Starting from `sum`, the contribution of the composites
+/// is removed one prime at a time. Only the values `x / i` are needed, and
+/// there are about `2 sqrt(x)` of them.
+fn lucy<T>(x: usize, sum: impl Fn(usize) -> T, f: impl Fn(usize) -> T) -> T
+where
+    T: Copy + PartialEq + Sub<Output = T> + Mul<Output = T>,
+{
+    let r = isqrt(x);
+    // small[v] = S(v) for v <= r, large[i] = S(x / i) for i <= r
+    let mut small: Vec<T> = (0..=r).map(&sum).collect();
+    let mut large: Vec<T> = (0..=r)
+        .map(|i| sum(x.checked_div(i).unwrap_or(0)))
+        .collect();
+
+    for p in 2..=r {
+        if small[p] == small[p - 1] {
+            // p is not prime
+            continue;
+        }
+        let below_p = small[p - 1];
+        let fp = f(p);
+        let p2 = p * p;
+
+        // Remove the composites whose smallest prime factor is p
+        for i in 1..=r.min(x / p2) {
+            let d = i * p;
+            let s = if d <= r { large[d] } else { small[x / d] };
+            large[i] = large[i] - fp * (s - below_p);
+        }
+        for v in (p2..=r).rev() {
+            small[v] = small[v] - fp * (small[v / p] - below_p);
+        }
+    }
+
+    if x == 0 {
+        small[0]
+    } else {
+        large[1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::Sieve;
+
+    #[test]
+    fn test_matches_sieve() {
+        let sieve = Sieve::new(5000);
+        let mut sum = 0;
+        for x in 0..=5000 {
+            if sieve.is_prime(x) {
+                sum += x as u128;
+            }
+            assert_eq!(prime_count(x), sieve.prime_count(x), "{x}");
+            assert_eq!(prime_sum(x), sum, "{x}");
+        }
+    }
+
+    #[test]
+    fn test_powers_of_ten() {
+        let expected = [
+            0, 4, 25, 168, 1229, 9592, 78498, 664579, 5761455, 50847534, 455052511,
+        ];
+        for (k, &count) in expected.iter().enumerate() {
+            assert_eq!(prime_count(10usize.pow(k as u32)), count, "10^{k}");
+        }
+    }
+
+    #[test]
+    fn test_prime_sum() {
+        assert_eq!(prime_sum(1000), 76127);
+        assert_eq!(prime_sum(1_000_000), 37550402023);
+        assert_eq!(prime_sum(1_000_000_000), 24739512092254535);
+    }
+}
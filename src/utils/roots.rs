@@ -0,0 +1,166 @@
+/// Integer square root: the largest `r` such that `r² <= n`.
+///
+/// # Examples
+/// ```
+/// use reuler::utils::isqrt;
+///
+/// assert_eq!(isqrt(0), 0);
+/// assert_eq!(isqrt(24), 4);
+/// assert_eq!(isqrt(25), 5);
+/// assert_eq!(isqrt(usize::MAX), 4294967295);
+/// ```
+pub fn isqrt(n: usize) -> usize {
+    iroot(n, 2)
+}
+
+/// Integer cube root: the largest `r` such that `r³ <= n`.
+///
+/// # Examples
+/// ```
+/// use reuler::utils::icbrt;
+///
+/// assert_eq!(icbrt(26), 2);
+/// assert_eq!(icbrt(27), 3);
+/// assert_eq!(icbrt(usize::MAX), 2642245);
+/// ```
+pub fn icbrt(n: usize) -> usize {
+    iroot(n, 3)
+}
+
+/// Integer k-th root: the largest `r` such that `r^k <= n`.
+///
+/// The root is computed exactly with Newton's method on integers, so there is
+/// no rounding issue for large numbers.
+///
+/// # Panics
+/// Panics if `k` is 0.
+///
+/// # Examples
+/// ```
+/// use reuler::utils::iroot;
+///
+/// assert_eq!(iroot(1023, 10), 1);
+/// assert_eq!(iroot(1024, 10), 2);
+/// assert_eq!(iroot(10usize.pow(18), 6), 1000);
+/// ```
+pub fn iroot(n: usize, k: u32) -> usize {
+    assert!(k > 0, "The 0-th root is not defined.");
+    if n < 2 || k == 1 {
+        return n;
+    }
+
+    // Start above the root, at 2^ceil(bits / k), and go down
+    let bits = usize::BITS - n.leading_zeros();
+    let mut x: usize = 1 << bits.div_ceil(k);
+    let k = k as usize;
+    loop {
+        // If x^(k - 1) overflows, n / x^(k - 1) is 0
+        let quotient = x.checked_pow(k as u32 - 1).map_or(0, |p| n / p);
+        // The sum only overflows when x is tiny, and then y is above x anyway
+        let y = ((k - 1) * x).saturating_add(quotient) / k;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+/// Check if the given number is a perfect square.
+///
+/// # Examples
+/// ```
+/// assert!(reuler::utils::is_square(0));
+/// assert!(reuler::utils::is_square(144));
+/// assert!(!reuler::utils::is_square(145));
+/// ```
+pub fn is_square(n: usize) -> bool {
+    let r = isqrt(n);
+    r * r == n
+}
+
+/// Check if the given number is a perfect power: `m^k` for some `m` and some
+/// `k >= 2`.
+///
+/// # Examples
+/// ```
+/// use reuler::utils::is_perfect_power;
+///
+/// assert!(is_perfect_power(1));
+/// assert!(is_perfect_power(32));
+/// assert!(is_perfect_power(3usize.pow(40)));
+/// assert!(!is_perfect_power(72));
+/// ```
+pub fn is_perfect_power(n: usize) -> bool {
+    if n < 4 {
+        return n < 2;
+    }
+    // n = m^k with k >= 2 needs m >= 2, so k <= log2(n)
+    let max_k = usize::BITS - 1 - n.leading_zeros();
+    (2..=max_k).any(|k| iroot(n, k).pow(k) == n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Check that r is the k-th root of n, without overflow.
+    fn is_root(n: usize, k: u32, r: usize) -> bool {
+        r.checked_pow(k).is_some_and(|p| p <= n)
+            && r.checked_add(1)
+                .and_then(|s| s.checked_pow(k))
+                .is_none_or(|p| p > n)
+    }
+
+    #[test]
+    fn test_small_roots() {
+        for n in 0..10_000 {
+            for k in 1..=12 {
+                assert!(is_root(n, k, iroot(n, k)), "{n} {k}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_around_powers() {
+        for k in 2..=64 {
+            for r in 2usize.. {
+                let Some(p) = r.checked_pow(k) else {
+                    break;
+                };
+                assert_eq!(iroot(p, k), r, "{r}^{k}");
+                assert_eq!(iroot(p - 1, k), r - 1, "{r}^{k} - 1");
+                if r > 1000 {
+                    break;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_large_numbers() {
+        // Where f64 square roots are wrong
+        let r = 94_906_267;
+        assert_eq!(isqrt(r * r - 1), r - 1);
+        assert!(!is_square(r * r - 1));
+        assert_eq!(isqrt(usize::MAX), u32::MAX as usize);
+        for k in 1..=70 {
+            assert!(is_root(usize::MAX, k, iroot(usize::MAX, k)), "{k}");
+        }
+    }
+
+    #[test]
+    fn test_is_perfect_power() {
+        let expected: Vec<usize> = vec![0, 1, 4, 8, 9, 16, 25, 27, 32, 36, 49, 64, 81, 100];
+        let powers: Vec<usize> = (0..=100).filter(|&n| is_perfect_power(n)).collect();
+        assert_eq!(powers, expected);
+        assert!(is_perfect_power(1 << 63));
+        assert!(!is_perfect_power((1 << 63) - 1));
+        assert!(!is_perfect_power(usize::MAX));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_zeroth_root() {
+        iroot(10, 0);
+    }
+}
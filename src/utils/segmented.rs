@@ -0,0 +1,201 @@
+use super::Sieve;
+
+/// Default number of integers covered by one segment.
+const SEGMENT_SIZE: usize = 1 << 20;
+
+/// Segmented Sieve of Eratosthenes, giving all the prime numbers in the
+/// interval `[lo, hi)`.
+///
+/// Only the primes up to `sqrt(hi)` and a single segment are kept in memory,
+/// so windows far above what a `Sieve` could hold can be explored: primes
+/// around 10^12 need less than 1 MB.
+///
+/// # Examples
+/// ```
+/// use reuler::utils::SegmentedPrimes;
+///
+/// let primes: Vec<usize> = SegmentedPrimes::new(10, 30).collect();
+/// assert_eq!(primes, vec![11, 13, 17, 19, 23, 29]);
+///
+/// let lo = 1_000_000_000_000;
+/// let primes: Vec<usize> = SegmentedPrimes::new(lo, lo + 100).collect();
+/// assert_eq!(primes, vec![1_000_000_000_039, 1_000_000_000_061, 1_000_000_000_063, 1_000_000_000_091]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SegmentedPrimes {
+    /// End of the interval (excluded).
+    hi: usize,
+    /// Number of integers covered by one segment.
+    segment_size: usize,
+    /// Odd primes up to `sqrt(hi)`.
+    base: Vec<usize>,
+    /// Start of the current segment. Always odd, segments only hold odd
+    /// numbers.
+    start: usize,
+    /// End of the current segment (excluded).
+    end: usize,
+    /// Bit `i` is set if `start + 2i` is composite.
+    composite: Vec<u64>,
+    /// Index of the next number to consider in the current segment.
+    next: usize,
+    /// Whether 2 is in the interval and wasn't given yet.
+    two: bool,
+}
+
+impl SegmentedPrimes {
+    /// Iterate over the primes in `[lo, hi)`, in increasing order.
+    pub fn new(lo: usize, hi: usize) -> Self {
+        Self::with_segment_size(lo, hi, SEGMENT_SIZE)
+    }
+
+    /// Iterate over the primes in `[lo, hi)`, sieving `segment_size` integers
+    /// at a time.
+    ///
+    /// # Panics
+    /// Panics if `segment_size` is 0.
+    pub fn with_segment_size(lo: usize, hi: usize, segment_size: usize) -> Self {
+        assert!(segment_size > 0, "The segment size can't be 0.");
+
+        let base = if hi > 2 {
+            Sieve::new(super::isqrt(hi - 1)).iter().skip(1).collect()
+        } else {
+            Vec::new()
+        };
+        // First odd number above 2 in the interval
+        let start = lo.max(3) | 1;
+
+        let mut primes = Self {
+            hi,
+            segment_size: segment_size.next_multiple_of(2),
+            base,
+            start,
+            end: start,
+            composite: Vec::new(),
+            next: 0,
+            two: lo <= 2 && 2 < hi,
+        };
+        primes.sieve_segment();
+        primes
+    }
+
+    /// Sieve the segment starting at `self.start`.
+    fn sieve_segment(&mut self) {
+        self.end = self.start.saturating_add(self.segment_size).min(self.hi);
+        self.next = 0;
+        if self.start >= self.end {
+            self.composite.clear();
+            return;
+        }
+
+        let n_odds = (self.end - self.start).div_ceil(2);
+        self.composite.clear();
+        self.composite.resize(n_odds.div_ceil(64), 0);
+
+        for &p in &self.base {
+            if p * p >= self.end {
+                break;
+            }
+            // First odd multiple of p in the segment, starting at p²
+            let Some(mut m) = self.start.div_ceil(p).checked_mul(p) else {
+                continue;
+            };
+            m = m.max(p * p);
+            if m.is_multiple_of(2) {
+                m = match m.checked_add(p) {
+                    Some(m) => m,
+                    None => continue,
+                };
+            }
+
+            let mut j = (m - self.start) / 2;
+            while j < n_odds {
+                self.composite[j / 64] |= 1 << (j % 64);
+                j += p;
+            }
+        }
+    }
+}
+
+impl Iterator for SegmentedPrimes {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.two {
+            self.two = false;
+            return Some(2);
+        }
+
+        while self.start < self.end {
+            // Skip whole words of composites, then find the next prime in the
+            // word
+            let n_odds = (self.end - self.start).div_ceil(2);
+            let mut i = self.next;
+            while i < n_odds {
+                let word = !self.composite[i / 64] >> (i % 64);
+                if word == 0 {
+                    i = (i / 64 + 1) * 64;
+                    continue;
+                }
+
+                i += word.trailing_zeros() as usize;
+                if i >= n_odds {
+                    break;
+                }
+                self.next = i + 1;
+                return Some(self.start + 2 * i);
+            }
+
+            self.start = self.end;
+            self.sieve_segment();
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_sieve() {
+        let sieve = Sieve::new(5000);
+        for (lo, hi) in [
+            (0, 0),
+            (0, 1),
+            (0, 3),
+            (2, 3),
+            (3, 4),
+            (0, 5000),
+            (1000, 1001),
+            (997, 5000),
+        ] {
+            for size in [1, 2, 7, 64, 128, 1000, SEGMENT_SIZE] {
+                let expected: Vec<usize> = sieve.iter().filter(|p| (lo..hi).contains(p)).collect();
+                let primes: Vec<usize> = SegmentedPrimes::with_segment_size(lo, hi, size).collect();
+                assert_eq!(primes, expected, "[{lo}, {hi}) by {size}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_empty_interval() {
+        assert_eq!(SegmentedPrimes::new(100, 10).count(), 0);
+        assert_eq!(SegmentedPrimes::new(24, 29).count(), 0);
+    }
+
+    #[test]
+    fn test_high_window() {
+        let sieve = Sieve::new(1_000_000);
+        let lo = 999_999_000_000;
+        let hi = lo + 10_000;
+        let primes: Vec<usize> = SegmentedPrimes::with_segment_size(lo, hi, 1000).collect();
+        let expected: Vec<usize> = (lo..hi).filter(|&n| sieve.is_prime(n)).collect();
+        assert_eq!(primes, expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_zero_segment_size() {
+        SegmentedPrimes::with_segment_size(0, 10, 0);
+    }
+}
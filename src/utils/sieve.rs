@@ -0,0 +1,217 @@
+/// Sieve of Eratosthenes, giving all the prime numbers up to a limit.
+///
+/// Only odd numbers are stored, one bit per number, so a sieve up to `n` takes
+/// about `n / 16` bytes.
+///
+/// # Examples
+/// ```
+/// use reuler::utils::Sieve;
+///
+/// let sieve = Sieve::new(30);
+///
+/// assert!(sieve.is_prime(29));
+/// assert!(!sieve.is_prime(27));
+/// assert_eq!(sieve.prime_count(30), 10);
+///
+/// let primes: Vec<usize> = sieve.iter().collect();
+/// assert_eq!(primes, vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Sieve {
+    limit: usize,
+    /// Bit `i` is set if the odd number `2i + 1` is composite (or 1).
+    composite: Vec<u64>,
+}
+
+impl Sieve {
+    /// Sieve all the prime numbers up to the given limit (included).
+    pub fn new(limit: usize) -> Self {
+        let n_odds = limit / 2 + limit % 2;
+        let mut composite = vec![0; n_odds.div_ceil(64).max(1)];
+
+        // 1 is not prime
+        composite[0] |= 1;
+
+        let mut i = 1;
+        while (2 * i + 1) * (2 * i + 1) <= limit {
+            if composite[i / 64] & (1 << (i % 64)) == 0 {
+                // Cross out the odd multiples of p, starting at p²
+                let p = 2 * i + 1;
+                let mut j = p * p / 2;
+                while j < n_odds {
+                    composite[j / 64] |= 1 << (j % 64);
+                    j += p;
+                }
+            }
+            i += 1;
+        }
+
+        Self { limit, composite }
+    }
+
+    /// The limit of the sieve.
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Check if the given number is prime.
+    ///
+    /// Numbers up to the limit of the sieve are looked up in constant time.
+    /// Numbers above the limit (but not above the square of the limit) are
+    /// checked by trial division with the sieved primes.
+    ///
+    /// # Panics
+    /// Panics if the number is above the square of the limit.
+    pub fn is_prime(&self, n: usize) -> bool {
+        if n <= self.limit {
+            return n == 2 || (n % 2 == 1 && !self.is_composite(n / 2));
+        }
+
+        assert!(
+            n <= self.limit.saturating_mul(self.limit),
+            "Can't check if {n} is prime with a sieve up to {}.",
+            self.limit
+        );
+        self.iter()
+            .take_while(|p| p * p <= n)
+            .all(|p| !n.is_multiple_of(p))
+    }
+
+    /// Number of primes up to `n` (included).
+    ///
+    /// # Panics
+    /// Panics if `n` is above the limit of the sieve.
+    pub fn prime_count(&self, n: usize) -> usize {
+        assert!(
+            n <= self.limit,
+            "Can't count the primes up to {n} with a sieve up to {}.",
+            self.limit
+        );
+        if n < 2 {
+            return 0;
+        }
+
+        // Count the odd primes among the odd numbers up to n, then add 2
+        let n_odds = n / 2 + n % 2;
+        let full_words = n_odds / 64;
+        let mut n_composites: usize = self.composite[..full_words]
+            .iter()
+            .map(|w| w.count_ones() as usize)
+            .sum();
+        if !n_odds.is_multiple_of(64) {
+            let mask = (1 << (n_odds % 64)) - 1;
+            n_composites += (self.composite[full_words] & mask).count_ones() as usize;
+        }
+        n_odds - n_composites + 1
+    }
+
+    /// Iterate over the primes of the sieve, in increasing order.
+    pub fn iter(&self) -> SievePrimes<'_> {
+        SievePrimes {
+            sieve: self,
+            next: 2,
+        }
+    }
+
+    fn is_composite(&self, i: usize) -> bool {
+        self.composite[i / 64] & (1 << (i % 64)) != 0
+    }
+}
+
+impl<'a> IntoIterator for &'a Sieve {
+    type Item = usize;
+    type IntoIter = SievePrimes<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the primes of a `Sieve`, in increasing order.
+pub struct SievePrimes<'a> {
+    sieve: &'a Sieve,
+    /// Next number to consider.
+    next: usize,
+}
+
+impl Iterator for SievePrimes<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next == 2 {
+            self.next = 3;
+            if self.sieve.limit >= 2 {
+                return Some(2);
+            }
+        }
+
+        // Skip whole words of composites, then find the next prime in the word
+        let n_odds = self.sieve.limit / 2 + self.sieve.limit % 2;
+        let mut i = self.next / 2;
+        while i < n_odds {
+            let word = !self.sieve.composite[i / 64] >> (i % 64);
+            if word == 0 {
+                i = (i / 64 + 1) * 64;
+                continue;
+            }
+
+            i += word.trailing_zeros() as usize;
+            if i >= n_odds {
+                break;
+            }
+            self.next = 2 * i + 3;
+            return Some(2 * i + 1);
+        }
+
+        self.next = self.sieve.limit + 1;
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils;
+
+    #[test]
+    fn test_matches_trial_division() {
+        let sieve = Sieve::new(10000);
+        for n in 0..=10000 {
+            assert_eq!(sieve.is_prime(n), n > 0 && utils::is_prime(n), "{n}");
+        }
+    }
+
+    #[test]
+    fn test_iter_matches_primes() {
+        for limit in [0, 1, 2, 3, 63, 64, 127, 128, 129, 1000] {
+            let sieve = Sieve::new(limit);
+            let expected: Vec<usize> = utils::Primes::new_up_to(limit).collect();
+            assert_eq!(sieve.iter().collect::<Vec<usize>>(), expected, "{limit}");
+        }
+    }
+
+    #[test]
+    fn test_prime_count() {
+        let sieve = Sieve::new(1_000_000);
+        assert_eq!(sieve.prime_count(0), 0);
+        assert_eq!(sieve.prime_count(2), 1);
+        assert_eq!(sieve.prime_count(10), 4);
+        assert_eq!(sieve.prime_count(100), 25);
+        assert_eq!(sieve.prime_count(1_000_000), 78498);
+        assert_eq!(sieve.iter().count(), 78498);
+    }
+
+    #[test]
+    fn test_is_prime_above_limit() {
+        let sieve = Sieve::new(100);
+        assert!(sieve.is_prime(9973));
+        assert!(!sieve.is_prime(9999));
+        assert!(!sieve.is_prime(101 * 97));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_is_prime_too_large() {
+        Sieve::new(100).is_prime(10001);
+    }
+}
@@ -0,0 +1,364 @@
+use super::bigint::{forward_binop, primitive_binop};
+use super::BigInt;
+use crate::error::Error;
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops;
+use std::str::FromStr;
+
+/// Signed arbitrarily large integer: a `BigInt` magnitude with a sign.
+///
+/// Supports the same operators as `BigInt`, between two SignedBigInt and with
+/// an `isize`, plus negation.
+///
+/// # Notes
+/// Like for the primitive signed integers, the division truncates toward
+/// zero, and the remainder has the sign of the dividend: `-7 / 2 = -3` and
+/// `-7 % 2 = -1`. A division by zero panics.
+///
+/// # Examples
+/// ```
+/// use reuler::utils::{BigInt, SignedBigInt};
+///
+/// let x = SignedBigInt::from(3) - SignedBigInt::from(10);
+/// assert_eq!(x.to_string(), "-7");
+/// assert_eq!((&x * &x).to_string(), "49");
+/// assert_eq!((&x / 2).to_string(), "-3");
+/// assert_eq!((&x % 2).to_string(), "-1");
+///
+/// assert_eq!(x.abs().to_unsigned(), Some(BigInt::from(7)));
+/// assert_eq!(x.to_unsigned(), None);
+/// assert!(x < SignedBigInt::from(BigInt::new()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SignedBigInt {
+    /// Always false for 0, so that 0 has a single representation.
+    negative: bool,
+    magnitude: BigInt,
+}
+
+impl SignedBigInt {
+    /// Create a new SignedBigInt, starting at 0.
+    pub fn new() -> Self {
+        Self::from(BigInt::new())
+    }
+
+    /// Create a SignedBigInt from a sign and a magnitude.
+    fn with_sign(negative: bool, magnitude: BigInt) -> Self {
+        Self {
+            negative: negative && !magnitude.is_zero(),
+            magnitude,
+        }
+    }
+
+    /// Absolute value of the number, as an unsigned `BigInt`.
+    pub fn magnitude(&self) -> &BigInt {
+        &self.magnitude
+    }
+
+    /// Absolute value of the number.
+    pub fn abs(&self) -> Self {
+        Self::from(self.magnitude.clone())
+    }
+
+    /// Check if the number is strictly negative.
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Check if the number is 0.
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_zero()
+    }
+
+    /// Convert to an unsigned `BigInt`, or `None` if the number is negative.
+    pub fn to_unsigned(&self) -> Option<BigInt> {
+        (!self.negative).then(|| self.magnitude.clone())
+    }
+
+    /// Quotient and remainder of the division by `x`, or `None` if `x` is 0.
+    pub fn checked_div_rem(&self, x: &SignedBigInt) -> Option<(SignedBigInt, SignedBigInt)> {
+        let (q, r) = self.magnitude.checked_div_rem(&x.magnitude)?;
+        Some((
+            Self::with_sign(self.negative != x.negative, q),
+            Self::with_sign(self.negative, r),
+        ))
+    }
+
+    /// Division, returning `None` if `x` is 0.
+    pub fn checked_div(&self, x: &SignedBigInt) -> Option<SignedBigInt> {
+        self.checked_div_rem(x).map(|(q, _)| q)
+    }
+
+    /// Remainder, returning `None` if `x` is 0.
+    pub fn checked_rem(&self, x: &SignedBigInt) -> Option<SignedBigInt> {
+        self.checked_div_rem(x).map(|(_, r)| r)
+    }
+}
+
+impl Default for SignedBigInt {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<BigInt> for SignedBigInt {
+    fn from(magnitude: BigInt) -> Self {
+        Self::with_sign(false, magnitude)
+    }
+}
+
+impl From<isize> for SignedBigInt {
+    fn from(x: isize) -> Self {
+        Self::with_sign(x < 0, BigInt::from(x.unsigned_abs()))
+    }
+}
+
+impl fmt::Display for SignedBigInt {
+    /// Write the number in decimal, with a leading `-` if it's negative.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.negative {
+            write!(f, "-")?;
+        }
+        write!(f, "{}", self.magnitude)
+    }
+}
+
+impl FromStr for SignedBigInt {
+    type Err = Error;
+
+    /// Parse a SignedBigInt from its decimal representation, with an
+    /// optional sign.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        Ok(Self::with_sign(negative, digits.parse()?))
+    }
+}
+
+impl Ord for SignedBigInt {
+    /// Compare two SignedBigInt by their value.
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+            (negative, _) => {
+                if negative {
+                    Ordering::Less
+                } else {
+                    Ordering::Greater
+                }
+            }
+        }
+    }
+}
+
+impl PartialOrd for SignedBigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl ops::Neg for &SignedBigInt {
+    type Output = SignedBigInt;
+
+    fn neg(self) -> Self::Output {
+        SignedBigInt::with_sign(!self.negative, self.magnitude.clone())
+    }
+}
+
+impl ops::Neg for SignedBigInt {
+    type Output = SignedBigInt;
+
+    fn neg(self) -> Self::Output {
+        SignedBigInt::with_sign(!self.negative, self.magnitude)
+    }
+}
+
+impl ops::Add<&SignedBigInt> for &SignedBigInt {
+    type Output = SignedBigInt;
+
+    /// Overload the addition for SignedBigInt.
+    fn add(self, x: &SignedBigInt) -> Self::Output {
+        if self.negative == x.negative {
+            return SignedBigInt::with_sign(self.negative, &self.magnitude + &x.magnitude);
+        }
+
+        // Different signs : the result has the sign of the larger magnitude
+        if self.magnitude >= x.magnitude {
+            SignedBigInt::with_sign(self.negative, &self.magnitude - &x.magnitude)
+        } else {
+            SignedBigInt::with_sign(x.negative, &x.magnitude - &self.magnitude)
+        }
+    }
+}
+
+impl ops::Sub<&SignedBigInt> for &SignedBigInt {
+    type Output = SignedBigInt;
+
+    /// Overload the subtraction for SignedBigInt.
+    fn sub(self, x: &SignedBigInt) -> Self::Output {
+        self + &(-x)
+    }
+}
+
+impl ops::Mul<&SignedBigInt> for &SignedBigInt {
+    type Output = SignedBigInt;
+
+    /// Overload the multiplication for SignedBigInt.
+    fn mul(self, x: &SignedBigInt) -> Self::Output {
+        SignedBigInt::with_sign(self.negative != x.negative, &self.magnitude * &x.magnitude)
+    }
+}
+
+impl ops::Div<&SignedBigInt> for &SignedBigInt {
+    type Output = SignedBigInt;
+
+    /// Overload the division for SignedBigInt, truncating toward zero.
+    ///
+    /// # Panics
+    /// Panics if `x` is 0.
+    fn div(self, x: &SignedBigInt) -> Self::Output {
+        self.checked_div(x).expect("attempt to divide by zero")
+    }
+}
+
+impl ops::Rem<&SignedBigInt> for &SignedBigInt {
+    type Output = SignedBigInt;
+
+    /// Overload the remainder for SignedBigInt. The result has the sign of
+    /// `self`.
+    ///
+    /// # Panics
+    /// Panics if `x` is 0.
+    fn rem(self, x: &SignedBigInt) -> Self::Output {
+        self.checked_rem(x)
+            .expect("attempt to calculate the remainder with a divisor of zero")
+    }
+}
+
+forward_binop!(SignedBigInt, Add, add, AddAssign, add_assign);
+forward_binop!(SignedBigInt, Sub, sub, SubAssign, sub_assign);
+forward_binop!(SignedBigInt, Mul, mul, MulAssign, mul_assign);
+forward_binop!(SignedBigInt, Div, div, DivAssign, div_assign);
+forward_binop!(SignedBigInt, Rem, rem, RemAssign, rem_assign);
+
+primitive_binop!(SignedBigInt, isize, Add, add, AddAssign, add_assign);
+primitive_binop!(SignedBigInt, isize, Sub, sub, SubAssign, sub_assign);
+primitive_binop!(SignedBigInt, isize, Mul, mul, MulAssign, mul_assign);
+primitive_binop!(SignedBigInt, isize, Div, div, DivAssign, div_assign);
+primitive_binop!(SignedBigInt, isize, Rem, rem, RemAssign, rem_assign);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Values covering both signs, carries and the whole `i64` range.
+    const VALUES: [i128; 16] = [
+        0,
+        1,
+        -1,
+        2,
+        -9,
+        10,
+        99,
+        -100,
+        999_999_999,
+        -1_000_000_000,
+        1_234_567_890_123_456_789,
+        i64::MAX as i128,
+        i64::MIN as i128,
+        1 << 64,
+        -(1 << 64),
+        -100_000_000_000_000_000_000_000_000_000,
+    ];
+
+    fn big(x: i128) -> SignedBigInt {
+        x.to_string().parse().unwrap()
+    }
+
+    #[test]
+    fn test_matches_i128() {
+        for a in VALUES {
+            for b in VALUES {
+                let (x, y) = (big(a), big(b));
+                assert_eq!(x.cmp(&y), a.cmp(&b), "{a} cmp {b}");
+                if let Some(sum) = a.checked_add(b) {
+                    assert_eq!(&x + &y, big(sum), "{a} + {b}");
+                }
+                if let Some(difference) = a.checked_sub(b) {
+                    assert_eq!(&x - &y, big(difference), "{a} - {b}");
+                }
+                if let Some(product) = a.checked_mul(b) {
+                    assert_eq!(&x * &y, big(product), "{a} * {b}");
+                }
+                assert_eq!(x.checked_div(&y), a.checked_div(b).map(big), "{a} / {b}");
+                assert_eq!(x.checked_rem(&y), a.checked_rem(b).map(big), "{a} % {b}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_zero_has_no_sign() {
+        let zero = SignedBigInt::new();
+        assert_eq!(-&zero, zero);
+        assert_eq!(SignedBigInt::from(5) - 5, zero);
+        assert_eq!(SignedBigInt::from(-5) * &zero, zero);
+        assert_eq!("-0".parse::<SignedBigInt>().unwrap(), zero);
+        assert_eq!(zero.to_string(), "0");
+        assert!(!zero.is_negative());
+    }
+
+    #[test]
+    fn test_neg_and_abs() {
+        let x = SignedBigInt::from(-42);
+        assert_eq!(-&x, SignedBigInt::from(42));
+        assert_eq!(x.abs(), SignedBigInt::from(42));
+        assert_eq!(x.magnitude(), &BigInt::from(42));
+        assert!(x.is_negative());
+    }
+
+    #[test]
+    fn test_conversions() {
+        let x = SignedBigInt::from(BigInt::from(12));
+        assert_eq!(x, SignedBigInt::from(12));
+        assert_eq!(x.to_unsigned(), Some(BigInt::from(12)));
+        assert_eq!((-x).to_unsigned(), None);
+        assert_eq!(
+            SignedBigInt::from(isize::MIN).to_string(),
+            isize::MIN.to_string()
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            "+12".parse::<SignedBigInt>().unwrap(),
+            SignedBigInt::from(12)
+        );
+        assert_eq!(
+            "-012".parse::<SignedBigInt>().unwrap(),
+            SignedBigInt::from(-12)
+        );
+        assert!("--1".parse::<SignedBigInt>().is_err());
+        assert!("-".parse::<SignedBigInt>().is_err());
+    }
+
+    #[test]
+    fn test_assign_operators() {
+        let mut x = SignedBigInt::from(10);
+        x -= 25;
+        x *= SignedBigInt::from(-3);
+        x /= &SignedBigInt::from(-4);
+        x %= 7;
+        assert_eq!(x, SignedBigInt::from((10 - 25) * -3 / -4 % 7));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_division_by_zero() {
+        let _ = SignedBigInt::from(-1) / 0;
+    }
+}
@@ -0,0 +1,189 @@
+//! Verification of the answers against a table of known-correct answers.
+//!
+//! The answers are not stored in clear, to avoid spoiling them : only a hash
+//! of each answer (as displayed) is stored, computed with [`hash()`].
+
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::problems;
+use std::fmt;
+
+/// Hashes of the known-correct answers, sorted by problem ID.
+const ANSWERS: &[(usize, u64)] = &[
+    (1, 0x8175b580cfe210b8),
+    (2, 0x2e1f84136c18aad5),
+    (3, 0x68ae1aea52da344f),
+    (4, 0xe3fc084125e76b23),
+    (5, 0x1b2ce7eef6290aab),
+    (6, 0xbfd32baf3784ae7d),
+    (7, 0xee1f8ef95816f0b0),
+    (8, 0xea5ca93bb295f9ac),
+    (9, 0xc226d112f4b237c9),
+    (10, 0x0ecd58df9ceed6f8),
+    (11, 0xcc70549d666d24bd),
+    (12, 0xe834dfaea5a36e25),
+    (13, 0x42fd27457a21fd3c),
+    (14, 0x43dc0258357b3978),
+    (15, 0x5dd8b6815e76a4a9),
+    (16, 0x270b54f106e424e5),
+    (17, 0x118a43030ee75481),
+    (18, 0x0d7f67f0f88c10e7),
+    (19, 0x4565301818197840),
+    (20, 0x3e131c1814555e6d),
+    (21, 0x9b36f7dfc9df0541),
+    (22, 0xa89a1cafe0f3aead),
+    (23, 0x97b27c77cda135ea),
+    (24, 0xbceae8b0a0d1533a),
+    (25, 0x4ae3b2d89762109a),
+    (26, 0x8ba98218402cde35),
+    (27, 0xd456935c8faca070),
+    (28, 0x4bf6957ac4023f3e),
+    (29, 0x40b1f634e969c8c2),
+    (30, 0x73ae488fe27d678a),
+    (31, 0xa9bef82f16380c41),
+    (32, 0x2ad0d21aa1683f3a),
+    (33, 0x4568b718181c937c),
+    (34, 0xb2b13102fbcebe21),
+    (35, 0x07eaff07b4ae847f),
+    (36, 0xe2bcf9c7cb82db20),
+    (37, 0x91782f03858ca9fb),
+    (38, 0x08f237c4030535b2),
+    (39, 0x9496b218454e2a3b),
+    (40, 0x603b4218278a7694),
+    (41, 0x220f8e6554beb481),
+    (42, 0x4561c91818169364),
+    (43, 0x8ce1f74977fb7c4d),
+    (44, 0x1fcc60347a41bf02),
+    (45, 0x11d2566b80783cba),
+    (46, 0xca301fcfc2070953),
+    (47, 0x18b76a6fbec57774),
+    (48, 0x22892c8c11f1b299),
+    (49, 0x13617280b4984eed),
+    (50, 0xc9488f8c8cdbc60c),
+    (51, 0x3147338f48733222),
+    (52, 0x2395ac8d1fb1d1b6),
+    (53, 0x43ac88d893b4991d),
+    (54, 0x573299182251953b),
+    (55, 0x6031371827820f5e),
+    (56, 0x8b8a8b1840123cf1),
+    (57, 0x455ec218181451a8),
+    (58, 0x63d778fa1f9546ca),
+    (59, 0x2244bd4b6c844f23),
+    (67, 0xe69596e17c4fd356),
+];
+
+/// Result of the verification of an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    /// The answer matches the known-correct answer.
+    Correct,
+    /// The answer doesn't match the known-correct answer.
+    Wrong,
+    /// There is no known-correct answer for this problem.
+    Unknown,
+}
+
+impl Verdict {
+    /// Name of the verdict, in lowercase.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Wrong => "wrong",
+            Verdict::Unknown => "unknown",
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Hash an answer, as stored in the table of known-correct answers (64-bit
+/// FNV-1a of the displayed answer).
+///
+/// # Examples
+/// ```
+/// use reuler::verify;
+///
+/// assert_eq!(verify::hash(&reuler::Answer::from(233168_usize)), 0x8175b580cfe210b8);
+/// ```
+pub fn hash(answer: &Answer) -> u64 {
+    let mut h: u64 = 0xcbf29ce484222325;
+    for b in answer.to_string().bytes() {
+        h ^= u64::from(b);
+        h = h.wrapping_mul(0x100000001b3);
+    }
+    h
+}
+
+/// Return `true` if there is a known-correct answer for the given problem.
+pub fn is_known(id: usize) -> bool {
+    ANSWERS.binary_search_by_key(&id, |&(i, _)| i).is_ok()
+}
+
+/// Check the given answer against the known-correct answer of the problem.
+///
+/// # Examples
+/// ```
+/// use reuler::verify::{self, Verdict};
+/// use reuler::Answer;
+///
+/// assert_eq!(verify::check(1, &Answer::from(233168_usize)), Verdict::Correct);
+/// assert_eq!(verify::check(1, &Answer::from(42_usize)), Verdict::Wrong);
+/// assert_eq!(verify::check(9999999, &Answer::from(42_usize)), Verdict::Unknown);
+/// ```
+pub fn check(id: usize, answer: &Answer) -> Verdict {
+    match ANSWERS.binary_search_by_key(&id, |&(i, _)| i) {
+        Ok(i) if ANSWERS[i].1 == hash(answer) => Verdict::Correct,
+        Ok(_) => Verdict::Wrong,
+        Err(_) => Verdict::Unknown,
+    }
+}
+
+/// Solve the given problem and check its answer against the known-correct
+/// answer.
+///
+/// # Errors
+/// An error is returned if the problem doesn't have an implementation yet, or
+/// if the solver fails.
+pub fn verify(id: usize) -> Result<Verdict, Error> {
+    let problem = problems::get(id).ok_or(Error::NotImplemented(id))?;
+    Ok(check(id, &problem.solve()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answers_sorted_and_unique() {
+        assert!(ANSWERS.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn test_hash() {
+        // Reference values of 64-bit FNV-1a
+        assert_eq!(hash(&Answer::from("")), 0xcbf29ce484222325);
+        assert_eq!(hash(&Answer::from("a")), 0xaf63dc4c8601ec8c);
+    }
+
+    #[test]
+    fn test_verify_unimplemented() {
+        assert_eq!(verify(9999999), Err(Error::NotImplemented(9999999)));
+    }
+
+    #[test]
+    fn test_every_problem_is_correct() {
+        let mut failures = Vec::new();
+        for p in problems::all() {
+            match verify(p.id()) {
+                Ok(Verdict::Correct) => (),
+                Ok(verdict) => failures.push(format!("#{} : {verdict}", p.id())),
+                Err(err) => failures.push(format!("#{} : {err}", p.id())),
+            }
+        }
+        assert!(failures.is_empty(), "Failed verifications : {failures:?}");
+    }
+}